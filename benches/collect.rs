@@ -0,0 +1,47 @@
+// Compare plain `to_vec` against the pre-sized collectors.
+// Run with `cargo bench`.
+
+#[macro_use]
+extern crate criterion;
+extern crate to_vec;
+
+use criterion::{Criterion,black_box};
+use to_vec::{ToVec,ToVecExact};
+
+const N: usize = 100_000;
+
+fn filtered(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter");
+    group.bench_function("to_vec", |b| b.iter(||
+        (0..N).filter(|i| i % 3 != 0).to_vec()
+    ));
+    group.bench_function("to_vec_with_capacity", |b| b.iter(||
+        (0..N).filter(|i| i % 3 != 0).to_vec_with_capacity(black_box(N))
+    ));
+    group.finish();
+}
+
+fn flat_mapped(c: &mut Criterion) {
+    let mut group = c.benchmark_group("flat_map");
+    group.bench_function("to_vec", |b| b.iter(||
+        (0..N / 4).flat_map(|i| vec![i; 4]).to_vec()
+    ));
+    group.bench_function("to_vec_with_capacity", |b| b.iter(||
+        (0..N / 4).flat_map(|i| vec![i; 4]).to_vec_with_capacity(black_box(N))
+    ));
+    group.finish();
+}
+
+fn exact(c: &mut Criterion) {
+    let mut group = c.benchmark_group("exact");
+    group.bench_function("to_vec", |b| b.iter(||
+        (0..N).map(|i| i * 2).to_vec()
+    ));
+    group.bench_function("to_vec_exact", |b| b.iter(||
+        (0..N).map(|i| i * 2).to_vec_exact()
+    ));
+    group.finish();
+}
+
+criterion_group!(benches, filtered, flat_mapped, exact);
+criterion_main!(benches);
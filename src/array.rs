@@ -0,0 +1,126 @@
+//! Collecting into fixed-size arrays, without allocating.
+
+use core::array;
+use error::{ArrayLengthError,ArrayResultError};
+
+/// to_array() method on iterators
+pub trait ToArray<T> {
+    /// collect exactly `N` values into an array
+    fn to_array<const N: usize>(self) -> Result<[T; N],ArrayLengthError>;
+}
+
+/// to_array_result() method on iterators
+pub trait ToArrayResult<T,E> {
+    /// this collects an iterator of `Result<T,E>` into an array,
+    /// stopping at the first error like `to_vec_result`
+    fn to_array_result<const N: usize>(self) -> Result<[T; N],ArrayResultError<E>>;
+}
+
+// we have `[Option<T>; N]`, which must be all `Some` for the array to be full,
+// and must then have nothing left over
+fn fill<T,I,const N: usize>(items: [Option<T>; N], rest: &mut I) -> Result<[T; N],ArrayLengthError>
+where I: Iterator {
+    let found = items.iter().take_while(|t| t.is_some()).count();
+    if found < N {
+        return Err(ArrayLengthError::TooFew{expected: N, found});
+    }
+    if rest.next().is_some() {
+        return Err(ArrayLengthError::TooMany{expected: N});
+    }
+    Ok(items.map(|t| t.expect("all items are present")))
+}
+
+impl <T,I> ToArray<T> for I
+where I: Iterator<Item=T> {
+    fn to_array<const N: usize>(self) -> Result<[T; N],ArrayLengthError> {
+        // from_fn asks for all N items, so don't go past the end
+        let mut iter = self.fuse();
+        let items = array::from_fn(|_| iter.next());
+        fill(items, &mut iter)
+    }
+}
+
+impl <T,E,I> ToArrayResult<T,E> for I
+where I: Iterator<Item=Result<T,E>> {
+    fn to_array_result<const N: usize>(self) -> Result<[T; N],ArrayResultError<E>> {
+        let mut iter = self.fuse();
+        let mut err = None;
+        let items = array::from_fn(|_| {
+            if err.is_some() {
+                return None;
+            }
+            match iter.next() {
+                Some(Ok(t)) => Some(t),
+                Some(Err(e)) => { err = Some(e); None },
+                None => None,
+            }
+        });
+        if let Some(e) = err {
+            return Err(ArrayResultError::Item(e));
+        }
+        fill(items, &mut iter).map_err(ArrayResultError::Length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // an iterator which carries on after returning None,
+    // counting how often it is asked
+    struct Unfused {
+        calls: usize,
+    }
+
+    impl Iterator for Unfused {
+        type Item = Result<usize,()>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.calls += 1;
+            if self.calls == 2 { None } else { Some(Ok(self.calls)) }
+        }
+    }
+
+    #[test]
+    fn test_to_array() {
+        let rgb: [u8; 3] = "255 128 0".split_whitespace()
+            .map(|s| s.parse().unwrap()).to_array().unwrap();
+        assert_eq!(rgb,[255,128,0]);
+
+        assert_eq!((0..2).to_array::<3>(),Err(ArrayLengthError::TooFew{expected: 3, found: 2}));
+        assert_eq!((0..).to_array::<3>(),Err(ArrayLengthError::TooMany{expected: 3}));
+        assert_eq!((0..0).to_array::<0>(),Ok([]));
+    }
+
+    #[test]
+    fn test_to_array_result() {
+        let ip = "192.168.0.1".split('.').map(|s| s.parse::<u8>()).to_array_result::<4>();
+        assert_eq!(ip.unwrap(),[192,168,0,1]);
+
+        let ip = "192.168.0".split('.').map(|s| s.parse::<u8>()).to_array_result::<4>();
+        assert_eq!(ip,Err(ArrayResultError::Length(ArrayLengthError::TooFew{expected: 4, found: 3})));
+
+        let ip = "192.168.0.1.2".split('.').map(|s| s.parse::<u8>()).to_array_result::<4>();
+        assert_eq!(ip,Err(ArrayResultError::Length(ArrayLengthError::TooMany{expected: 4})));
+
+        // stops at the first error
+        let mut seen = 0;
+        let ip = "192.x.0.y".split('.').inspect(|_| seen += 1)
+            .map(|s| s.parse::<u8>()).to_array_result::<4>();
+        assert!(matches!(ip,Err(ArrayResultError::Item(_))));
+        assert_eq!(seen,2);
+    }
+
+    #[test]
+    fn test_to_array_stops_at_none() {
+        let mut iter = Unfused{calls: 0};
+        let res = iter.by_ref().map(|r| r.unwrap()).to_array::<3>();
+        assert_eq!(res,Err(ArrayLengthError::TooFew{expected: 3, found: 1}));
+        assert_eq!(iter.calls,2);
+
+        let mut iter = Unfused{calls: 0};
+        let res = iter.by_ref().to_array_result::<3>();
+        assert_eq!(res,Err(ArrayResultError::Length(ArrayLengthError::TooFew{expected: 3, found: 1})));
+        assert_eq!(iter.calls,2);
+    }
+}
@@ -0,0 +1,108 @@
+//! Collectors which refuse to take more than a given number of items.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap,HashSet};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use error::TooMany;
+
+/// to_vec_bounded() method on iterators
+pub trait ToVecBounded<T> {
+    /// collect at most `max` values into a Vec. If there are more,
+    /// the error carries the first `max` values.
+    fn to_vec_bounded(self, max: usize) -> Result<Vec<T>,TooMany<Vec<T>>>;
+}
+
+/// to_map_bounded() method on iterators of references
+#[cfg(feature = "std")]
+pub trait ToMapBounded<K,V> {
+    /// collect at most `max` references into a HashMap by cloning.
+    /// If there are more, the error carries the map of the first `max`.
+    fn to_map_bounded(self, max: usize) -> Result<HashMap<K,V>,TooMany<HashMap<K,V>>>;
+}
+
+/// to_set_bounded() method on iterators of references
+#[cfg(feature = "std")]
+pub trait ToSetBounded<K> {
+    /// collect at most `max` values into a HashSet by cloning.
+    /// If there are more, the error carries the set of the first `max`.
+    fn to_set_bounded(self, max: usize) -> Result<HashSet<K>,TooMany<HashSet<K>>>;
+}
+
+// Collect up to `max` items. The iterator is advanced at most `max + 1` times,
+// so an endless iterator is fine.
+fn bounded<T,C,I>(mut iter: I, max: usize) -> Result<C,TooMany<C>>
+where C: Default + Extend<T>, I: Iterator<Item=T> {
+    let mut c = C::default();
+    c.extend(iter.by_ref().take(max));
+    if iter.next().is_some() {
+        Err(TooMany{max, partial: c})
+    } else {
+        Ok(c)
+    }
+}
+
+impl <T,I> ToVecBounded<T> for I
+where I: Iterator<Item=T> {
+    fn to_vec_bounded(self, max: usize) -> Result<Vec<T>,TooMany<Vec<T>>> {
+        bounded(self,max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K,V,I> ToMapBounded<K,V> for I
+where K: Eq + Hash + Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)> {
+    fn to_map_bounded(self, max: usize) -> Result<HashMap<K,V>,TooMany<HashMap<K,V>>> {
+        bounded(self.cloned(),max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K,I> ToSetBounded<K> for I
+where K: Eq + Hash + Clone +'a, I: Iterator<Item=&'a K> {
+    fn to_set_bounded(self, max: usize) -> Result<HashSet<K>,TooMany<HashSet<K>>> {
+        bounded(self.cloned(),max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_vec_bounded() {
+        let v = (0..3).to_vec_bounded(3).unwrap();
+        assert_eq!(v,&[0,1,2]);
+
+        let err = (0..).to_vec_bounded(3).unwrap_err();
+        assert_eq!(err,TooMany{max: 3, partial: vec![0,1,2]});
+
+        assert_eq!((0..0).to_vec_bounded(0),Ok(vec![]));
+        assert!((0..1).to_vec_bounded(0).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_bounded() {
+        use alloc::string::ToString;
+        const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+
+        let map = VALUES.iter().to_map_bounded(2).unwrap();
+        assert_eq!(map["dolly"],20);
+
+        let err = VALUES.iter().to_map_bounded(1).unwrap_err();
+        assert_eq!(err.partial.len(),1);
+        assert_eq!(err.to_string(),"more than 1 items");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_set_bounded() {
+        let set = [10,5,2,5,10].iter().to_set_bounded(5).unwrap();
+        assert_eq!(set.len(),3);
+
+        let err = [10,5,2,5,10].iter().to_set_bounded(4).unwrap_err();
+        assert_eq!(err.partial.len(),3);
+    }
+}
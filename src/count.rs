@@ -0,0 +1,134 @@
+//! Counting how often each value occurs.
+
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::cmp::Reverse;
+
+/// to_counts() methods on iterators of references
+pub trait ToCounts<K> {
+    /// count occurrences of each value in a HashMap, cloning the keys
+    #[cfg(feature = "std")]
+    fn to_counts(self) -> HashMap<K,usize>
+    where K: Eq + Hash;
+
+    /// count occurrences of each value in a BTreeMap, cloning the keys
+    fn to_btree_counts(self) -> BTreeMap<K,usize>
+    where K: Ord;
+}
+
+/// into_counts() methods on iterators of owned values
+pub trait IntoCounts<K> {
+    /// count occurrences of each value in a HashMap
+    #[cfg(feature = "std")]
+    fn into_counts(self) -> HashMap<K,usize>
+    where K: Eq + Hash;
+
+    /// count occurrences of each value in a BTreeMap
+    fn into_btree_counts(self) -> BTreeMap<K,usize>
+    where K: Ord;
+}
+
+/// most_common() method on the maps returned by `to_counts`
+pub trait MostCommon<K> {
+    /// the `n` most common values with their counts, largest first.
+    /// Ties keep the map's own iteration order, so they are in key
+    /// order for a `BTreeMap` and arbitrary for a `HashMap`.
+    fn most_common(&self, n: usize) -> Vec<(&K,usize)>;
+}
+
+impl <'a, K,I> ToCounts<K> for I
+where K: Clone +'a, I: Iterator<Item=&'a K> {
+    #[cfg(feature = "std")]
+    fn to_counts(self) -> HashMap<K,usize>
+    where K: Eq + Hash {
+        self.cloned().into_counts()
+    }
+
+    fn to_btree_counts(self) -> BTreeMap<K,usize>
+    where K: Ord {
+        self.cloned().into_btree_counts()
+    }
+}
+
+impl <K,I> IntoCounts<K> for I
+where I: Iterator<Item=K> {
+    #[cfg(feature = "std")]
+    fn into_counts(self) -> HashMap<K,usize>
+    where K: Eq + Hash {
+        let mut map = HashMap::new();
+        for k in self {
+            *map.entry(k).or_insert(0) += 1;
+        }
+        map
+    }
+
+    fn into_btree_counts(self) -> BTreeMap<K,usize>
+    where K: Ord {
+        let mut map = BTreeMap::new();
+        for k in self {
+            *map.entry(k).or_insert(0) += 1;
+        }
+        map
+    }
+}
+
+fn most_common<'a, K,I>(iter: I, n: usize) -> Vec<(&'a K,usize)>
+where K: 'a, I: Iterator<Item=(&'a K,&'a usize)> {
+    let mut counts: Vec<_> = iter.map(|(k,&c)| (k,c)).collect();
+    // sort_by_key is stable, which is what keeps ties in map order
+    counts.sort_by_key(|&(_,c)| Reverse(c));
+    counts.truncate(n);
+    counts
+}
+
+#[cfg(feature = "std")]
+impl <K,S> MostCommon<K> for HashMap<K,usize,S> {
+    fn most_common(&self, n: usize) -> Vec<(&K,usize)> {
+        most_common(self.iter(),n)
+    }
+}
+
+impl <K> MostCommon<K> for BTreeMap<K,usize> {
+    fn most_common(&self, n: usize) -> Vec<(&K,usize)> {
+        most_common(self.iter(),n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_counts() {
+        let counts = [1,2,1,3,1,2].iter().to_counts();
+        assert_eq!(counts[&1],3);
+        assert_eq!(counts[&3],1);
+
+        let counts = "the cat saw the dog".split_whitespace().into_counts();
+        assert_eq!(counts["the"],2);
+        assert_eq!(counts.len(),4);
+        assert_eq!(counts.most_common(1),&[(&"the",2)]);
+        assert_eq!(counts.most_common(0),&[]);
+    }
+
+    #[test]
+    fn test_to_btree_counts() {
+        let counts = ["b","a","b"].iter().to_btree_counts();
+        assert_eq!(counts.into_iter().collect::<Vec<_>>(),&[("a",1),("b",2)]);
+
+        let counts = "abracadabra".chars().into_btree_counts();
+        assert_eq!(counts[&'a'],5);
+    }
+
+    #[test]
+    fn test_most_common() {
+        let counts = "abracadabra".chars().into_btree_counts();
+        assert_eq!(counts.most_common(3),&[(&'a',5),(&'b',2),(&'r',2)]);
+        assert_eq!(counts.most_common(10).len(),5);
+    }
+}
@@ -0,0 +1,125 @@
+//! Error types returned by the fallible collectors.
+
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+use alloc::collections::TryReserveError;
+
+/// a key turned up more than once when collecting a map
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct DuplicateKey<K> {
+    /// the offending key
+    pub key: K,
+    /// position of the item which first used this key
+    pub first: usize,
+    /// position of the item which used it again
+    pub second: usize,
+}
+
+impl <K: fmt::Debug> fmt::Display for DuplicateKey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "duplicate key {:?} at positions {} and {}", self.key, self.first, self.second)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K: fmt::Debug> Error for DuplicateKey<K> {}
+
+/// memory for the collection could not be allocated
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct AllocError {
+    /// how many items had been collected when allocation failed
+    pub collected: usize,
+    /// the underlying error from `try_reserve`
+    pub source: TryReserveError,
+}
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "allocation failed after {} items: {}", self.collected, self.source)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AllocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// the iterator had more than the allowed number of items
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct TooMany<C> {
+    /// the maximum number of items allowed
+    pub max: usize,
+    /// the first `max` items, collected
+    pub partial: C,
+}
+
+impl <C> fmt::Display for TooMany<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "more than {} items", self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <C: fmt::Debug> Error for TooMany<C> {}
+
+/// the iterator didn't have exactly as many items as the array
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ArrayLengthError {
+    /// the iterator ran out after `found` items
+    TooFew {
+        /// the length of the array
+        expected: usize,
+        /// how many items there were
+        found: usize,
+    },
+    /// the iterator had more than `expected` items
+    TooMany {
+        /// the length of the array
+        expected: usize,
+    },
+}
+
+impl fmt::Display for ArrayLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArrayLengthError::TooFew{expected, found} =>
+                write!(f, "expected {} items, found only {}", expected, found),
+            ArrayLengthError::TooMany{expected} =>
+                write!(f, "expected {} items, found more", expected),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ArrayLengthError {}
+
+/// either an item was an error, or there were the wrong number of items
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum ArrayResultError<E> {
+    /// the first error found among the items
+    Item(E),
+    /// the items were fine, but there were too few or too many
+    Length(ArrayLengthError),
+}
+
+impl <E: fmt::Display> fmt::Display for ArrayResultError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArrayResultError::Item(ref e) => e.fmt(f),
+            ArrayResultError::Length(ref e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl <E: Error + 'static> Error for ArrayResultError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ArrayResultError::Item(ref e) => Some(e),
+            ArrayResultError::Length(ref e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Collecting into maps of groups, e.g. `HashMap<K,Vec<V>>`.
+
+use alloc::vec::Vec;
+use alloc::collections::{BTreeMap,BTreeSet};
+#[cfg(feature = "std")]
+use std::collections::{HashMap,HashSet};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
+/// to_group_map() and friends on iterators of references to pairs
+pub trait ToGroupMap<K,V> {
+    /// collect references into a HashMap of vectors by cloning
+    #[cfg(feature = "std")]
+    fn to_group_map(self) -> HashMap<K,Vec<V>>
+    where K: Eq + Hash;
+
+    /// collect references into a BTreeMap of vectors by cloning
+    fn to_group_btree_map(self) -> BTreeMap<K,Vec<V>>
+    where K: Ord;
+
+    /// collect references into a HashMap of sets by cloning
+    #[cfg(feature = "std")]
+    fn to_group_set_map(self) -> HashMap<K,HashSet<V>>
+    where K: Eq + Hash, V: Eq + Hash;
+
+    /// collect references into a BTreeMap of sets by cloning
+    fn to_group_btree_set_map(self) -> BTreeMap<K,BTreeSet<V>>
+    where K: Ord, V: Ord;
+}
+
+/// into_group_map() and friends on iterators of owned pairs
+pub trait IntoGroupMap<K,V> {
+    /// collect pairs into a HashMap of vectors without cloning
+    #[cfg(feature = "std")]
+    fn into_group_map(self) -> HashMap<K,Vec<V>>
+    where K: Eq + Hash;
+
+    /// collect pairs into a BTreeMap of vectors without cloning
+    fn into_group_btree_map(self) -> BTreeMap<K,Vec<V>>
+    where K: Ord;
+
+    /// collect pairs into a HashMap of sets without cloning
+    #[cfg(feature = "std")]
+    fn into_group_set_map(self) -> HashMap<K,HashSet<V>>
+    where K: Eq + Hash, V: Eq + Hash;
+
+    /// collect pairs into a BTreeMap of sets without cloning
+    fn into_group_btree_set_map(self) -> BTreeMap<K,BTreeSet<V>>
+    where K: Ord, V: Ord;
+}
+
+/// to_group_map_by() methods on iterators of references
+pub trait ToGroupMapBy<T> {
+    /// group cloned items into a HashMap using `key(&item)`
+    #[cfg(feature = "std")]
+    fn to_group_map_by<K,F>(self, key: F) -> HashMap<K,Vec<T>>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// group cloned items into a BTreeMap using `key(&item)`
+    fn to_group_btree_map_by<K,F>(self, key: F) -> BTreeMap<K,Vec<T>>
+    where K: Ord, F: FnMut(&T) -> K;
+
+    /// group cloned items into a HashMap of sets using `key(&item)`
+    #[cfg(feature = "std")]
+    fn to_group_set_map_by<K,F>(self, key: F) -> HashMap<K,HashSet<T>>
+    where K: Eq + Hash, T: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// group cloned items into a BTreeMap of sets using `key(&item)`
+    fn to_group_btree_set_map_by<K,F>(self, key: F) -> BTreeMap<K,BTreeSet<T>>
+    where K: Ord, T: Ord, F: FnMut(&T) -> K;
+}
+
+/// into_group_map_by() methods on iterators of owned values
+pub trait IntoGroupMapBy<T> {
+    /// group items into a HashMap using `key(&item)`
+    #[cfg(feature = "std")]
+    fn into_group_map_by<K,F>(self, key: F) -> HashMap<K,Vec<T>>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// group items into a BTreeMap using `key(&item)`
+    fn into_group_btree_map_by<K,F>(self, key: F) -> BTreeMap<K,Vec<T>>
+    where K: Ord, F: FnMut(&T) -> K;
+
+    /// group items into a HashMap of sets using `key(&item)`
+    #[cfg(feature = "std")]
+    fn into_group_set_map_by<K,F>(self, key: F) -> HashMap<K,HashSet<T>>
+    where K: Eq + Hash, T: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// group items into a BTreeMap of sets using `key(&item)`
+    fn into_group_btree_set_map_by<K,F>(self, key: F) -> BTreeMap<K,BTreeSet<T>>
+    where K: Ord, T: Ord, F: FnMut(&T) -> K;
+}
+
+// the groups may be any collection we can push a value onto
+#[cfg(feature = "std")]
+fn group_hash<K,V,C,I>(iter: I) -> HashMap<K,C>
+where K: Eq + Hash, C: Default + Extend<V>, I: Iterator<Item=(K,V)> {
+    let mut map = HashMap::new();
+    for (k,v) in iter {
+        map.entry(k).or_insert_with(C::default).extend(Some(v));
+    }
+    map
+}
+
+fn group_btree<K,V,C,I>(iter: I) -> BTreeMap<K,C>
+where K: Ord, C: Default + Extend<V>, I: Iterator<Item=(K,V)> {
+    let mut map = BTreeMap::new();
+    for (k,v) in iter {
+        map.entry(k).or_insert_with(C::default).extend(Some(v));
+    }
+    map
+}
+
+impl <'a, K,V,I> ToGroupMap<K,V> for I
+where K: Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)> {
+    #[cfg(feature = "std")]
+    fn to_group_map(self) -> HashMap<K,Vec<V>>
+    where K: Eq + Hash {
+        group_hash(self.cloned())
+    }
+
+    fn to_group_btree_map(self) -> BTreeMap<K,Vec<V>>
+    where K: Ord {
+        group_btree(self.cloned())
+    }
+
+    #[cfg(feature = "std")]
+    fn to_group_set_map(self) -> HashMap<K,HashSet<V>>
+    where K: Eq + Hash, V: Eq + Hash {
+        group_hash(self.cloned())
+    }
+
+    fn to_group_btree_set_map(self) -> BTreeMap<K,BTreeSet<V>>
+    where K: Ord, V: Ord {
+        group_btree(self.cloned())
+    }
+}
+
+impl <K,V,I> IntoGroupMap<K,V> for I
+where I: Iterator<Item=(K,V)> {
+    #[cfg(feature = "std")]
+    fn into_group_map(self) -> HashMap<K,Vec<V>>
+    where K: Eq + Hash {
+        group_hash(self)
+    }
+
+    fn into_group_btree_map(self) -> BTreeMap<K,Vec<V>>
+    where K: Ord {
+        group_btree(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn into_group_set_map(self) -> HashMap<K,HashSet<V>>
+    where K: Eq + Hash, V: Eq + Hash {
+        group_hash(self)
+    }
+
+    fn into_group_btree_set_map(self) -> BTreeMap<K,BTreeSet<V>>
+    where K: Ord, V: Ord {
+        group_btree(self)
+    }
+}
+
+impl <'a, T,I> ToGroupMapBy<T> for I
+where T: Clone +'a, I: Iterator<Item=&'a T> {
+    #[cfg(feature = "std")]
+    fn to_group_map_by<K,F>(self, key: F) -> HashMap<K,Vec<T>>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        self.cloned().into_group_map_by(key)
+    }
+
+    fn to_group_btree_map_by<K,F>(self, key: F) -> BTreeMap<K,Vec<T>>
+    where K: Ord, F: FnMut(&T) -> K {
+        self.cloned().into_group_btree_map_by(key)
+    }
+
+    #[cfg(feature = "std")]
+    fn to_group_set_map_by<K,F>(self, key: F) -> HashMap<K,HashSet<T>>
+    where K: Eq + Hash, T: Eq + Hash, F: FnMut(&T) -> K {
+        self.cloned().into_group_set_map_by(key)
+    }
+
+    fn to_group_btree_set_map_by<K,F>(self, key: F) -> BTreeMap<K,BTreeSet<T>>
+    where K: Ord, T: Ord, F: FnMut(&T) -> K {
+        self.cloned().into_group_btree_set_map_by(key)
+    }
+}
+
+impl <T,I> IntoGroupMapBy<T> for I
+where I: Iterator<Item=T> {
+    #[cfg(feature = "std")]
+    fn into_group_map_by<K,F>(self, mut key: F) -> HashMap<K,Vec<T>>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        group_hash(self.map(|t| (key(&t),t)))
+    }
+
+    fn into_group_btree_map_by<K,F>(self, mut key: F) -> BTreeMap<K,Vec<T>>
+    where K: Ord, F: FnMut(&T) -> K {
+        group_btree(self.map(|t| (key(&t),t)))
+    }
+
+    #[cfg(feature = "std")]
+    fn into_group_set_map_by<K,F>(self, mut key: F) -> HashMap<K,HashSet<T>>
+    where K: Eq + Hash, T: Eq + Hash, F: FnMut(&T) -> K {
+        group_hash(self.map(|t| (key(&t),t)))
+    }
+
+    fn into_group_btree_set_map_by<K,F>(self, mut key: F) -> BTreeMap<K,BTreeSet<T>>
+    where K: Ord, T: Ord, F: FnMut(&T) -> K {
+        group_btree(self.map(|t| (key(&t),t)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETS: &[(&str,&str)] = &[("dog","rex"),("cat","tom"),("dog","fido"),("dog","rex")];
+    const WORDS: &[&str] = &["apple","avocado","banana","cherry","blueberry"];
+
+    // deliberately not Clone
+    #[derive(Debug,PartialEq)]
+    struct Pet(&'static str);
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_group_map() {
+        let map = PETS.iter().to_group_map();
+        assert_eq!(map["dog"],&["rex","fido","rex"]);
+        assert_eq!(map["cat"],&["tom"]);
+
+        let map = PETS.iter().to_group_set_map();
+        assert_eq!(map["dog"].len(),2);
+
+        let map = PETS.iter().map(|&(k,v)| (k,Pet(v))).into_group_map();
+        assert_eq!(map["cat"],&[Pet("tom")]);
+
+        let map = PETS.iter().cloned().into_group_set_map();
+        assert_eq!(map["dog"].len(),2);
+    }
+
+    #[test]
+    fn test_to_group_btree_map() {
+        let map = PETS.iter().to_group_btree_map();
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(),&["cat","dog"]);
+
+        let map = PETS.iter().to_group_btree_set_map();
+        assert_eq!(map["dog"].iter().cloned().collect::<Vec<_>>(),&["fido","rex"]);
+
+        let map = PETS.iter().map(|&(k,v)| (k,Pet(v))).into_group_btree_map();
+        assert_eq!(map["dog"].len(),3);
+
+        let map = PETS.iter().cloned().into_group_btree_set_map();
+        assert_eq!(map.len(),2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_group_map_by() {
+        let map = WORDS.iter().to_group_map_by(|w| w.chars().next().unwrap());
+        assert_eq!(map[&'a'],&["apple","avocado"]);
+
+        let map = WORDS.iter().map(|w| Pet(w)).into_group_map_by(|p| p.0.len());
+        assert_eq!(map[&6],&[Pet("banana"),Pet("cherry")]);
+
+        let map = WORDS.iter().chain(WORDS).to_group_set_map_by(|w| w.len());
+        assert_eq!(map[&6].len(),2);
+        assert!(map[&6].contains("cherry"));
+
+        let map = WORDS.iter().chain(WORDS).cloned().into_group_set_map_by(|w| w.starts_with('b'));
+        assert_eq!(map[&true].len(),2);
+    }
+
+    #[test]
+    fn test_group_btree_map_by() {
+        let map = WORDS.iter().to_group_btree_map_by(|w| w.len());
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(),&[5,6,7,9]);
+
+        let map = WORDS.iter().map(|w| Pet(w)).into_group_btree_map_by(|p| p.0.len() > 6);
+        assert_eq!(map[&true],&[Pet("avocado"),Pet("blueberry")]);
+
+        let map = WORDS.iter().chain(WORDS).to_group_btree_set_map_by(|w| w.chars().next().unwrap());
+        assert_eq!(map[&'b'].iter().cloned().collect::<Vec<_>>(),&["banana","blueberry"]);
+
+        let map = WORDS.iter().chain(WORDS).cloned().into_group_btree_set_map_by(|w| w.len() > 6);
+        assert_eq!(map[&false].iter().cloned().collect::<Vec<_>>(),&["apple","banana","cherry"]);
+    }
+}
@@ -0,0 +1,59 @@
+//! Collecting into a `String`.
+
+use alloc::string::String;
+use core::fmt::{Display,Write};
+use core::iter::FromIterator;
+
+/// to_string_joined() method on iterators of displayable values
+pub trait ToStringJoined {
+    /// write each value into one String, separated by `sep`
+    fn to_string_joined(self, sep: &str) -> String;
+}
+
+/// to_string_concat() method on iterators of chars and strings
+pub trait ToStringConcat {
+    /// collect chars or string slices into a String
+    fn to_string_concat(self) -> String;
+}
+
+impl <T,I> ToStringJoined for I
+where T: Display, I: Iterator<Item=T> {
+    fn to_string_joined(self, sep: &str) -> String {
+        let mut s = String::new();
+        for (i,t) in self.enumerate() {
+            if i > 0 {
+                s.push_str(sep);
+            }
+            // writing to a String can't fail
+            write!(s, "{}", t).unwrap();
+        }
+        s
+    }
+}
+
+impl <T,I> ToStringConcat for I
+where String: FromIterator<T>, I: Iterator<Item=T> {
+    fn to_string_concat(self) -> String {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string_joined() {
+        assert_eq!([1,2,3].iter().to_string_joined(", "),"1, 2, 3");
+        assert_eq!(["one"].iter().to_string_joined(", "),"one");
+        assert_eq!((0..0).to_string_joined(", "),"");
+        assert_eq!((1..4).map(|i| i as f32 / 2.0).to_string_joined("|"),"0.5|1|1.5");
+    }
+
+    #[test]
+    fn test_to_string_concat() {
+        assert_eq!("hello".chars().rev().to_string_concat(),"olleh");
+        assert_eq!(["one","two","three"].iter().cloned().to_string_concat(),"onetwothree");
+        assert_eq!(vec![String::from("a"),String::from("b")].into_iter().to_string_concat(),"ab");
+    }
+}
@@ -23,6 +23,7 @@
 //! of _references_ and implicitly clone this.
 //!
 //! ```
+//! # #[cfg(feature = "std")] {
 //! use to_vec::ToMap;
 //! const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
 //!
@@ -30,31 +31,505 @@
 //!
 //! assert_eq!(map.get("hello"),Some(&10));
 //! assert_eq!(map.get("dolly"),Some(&20));
+//! # }
 //! ```
 //!
 //! This implicit cloning behaviour is very useful for sets (here defined
 //! as `HashSet`):
 //!
 //! ```
+//! # #[cfg(feature = "std")] {
 //! use to_vec::ToSet;
 //!
 //! let colours = ["green","orange","blue"].iter().to_set();
 //! let fruit = ["apple","banana","orange"].iter().to_set();
 //! let common = colours.intersection(&fruit).to_set();
 //! assert_eq!(common, ["orange"].iter().to_set());
+//! # }
 //! ```
+//!
+//! ## Owned values
+//!
+//! `to_map` and `to_set` need their keys and values to be `Clone`. When the
+//! iterator already yields owned values, `into_map` and `into_set` move
+//! them instead:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{IntoMap,IntoSet};
+//!
+//! let map = "one two".split_whitespace()
+//!     .map(|s| (s, s.to_string())).into_map();
+//! assert_eq!(map["two"], "two");
+//!
+//! let set = vec![String::from("a"), String::from("a")].into_iter().into_set();
+//! assert_eq!(set.len(), 1);
+//! # }
+//! ```
+//!
+//! ## Ordered maps and sets
+//!
+//! When a deterministic order matters, `to_btree_map` and `to_btree_set`
+//! do the same job with `BTreeMap` and `BTreeSet`:
+//!
+//! ```
+//! use to_vec::ToBTreeSet;
+//!
+//! let colours = ["green","orange","blue"].iter().to_btree_set();
+//! let v: Vec<_> = colours.into_iter().collect();
+//! assert_eq!(v, &["blue","green","orange"]);
+//! ```
+//!
+//! If you already have owned values, `into_btree_map` and `into_btree_set`
+//! consume them without cloning:
+//!
+//! ```
+//! use to_vec::IntoBTreeMap;
+//!
+//! let map = vec![(2,"two".to_string()),(1,"one".to_string())]
+//!     .into_iter().into_btree_map();
+//! assert_eq!(map.keys().collect::<Vec<_>>(), &[&1,&2]);
+//! ```
+//!
+//! ## Errors and missing values
+//!
+//! If you would rather see _every_ error, say when validating a batch of
+//! input, then `to_vec_all_errors` keeps going and returns `Result<Vec<T>,Vec<E>>`.
+//! `to_vec_indexed_errors` also tells you where each error happened:
+//!
+//! ```
+//! use to_vec::ToVecAllErrors;
+//!
+//! let res = "23E X 5F5 Y".split_whitespace()
+//!     .map(|s| u32::from_str_radix(s,16)).to_vec_indexed_errors();
+//!
+//! let bad = res.unwrap_err().into_iter().map(|(i,_)| i).collect::<Vec<_>>();
+//! assert_eq!(bad, &[1,3]);
+//! ```
+//!
+//! `to_vec_and_errors` and `to_vec_and_indexed_errors` return both
+//! the values and the errors as a pair.
+//!
+//! Maps and sets can be collected from results too, with `to_map_result`,
+//! `to_set_result` and their ordered cousins `to_btree_map_result` and
+//! `to_btree_set_result`. Like `to_vec_result`, they stop at the first error:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::ToMapResult;
+//!
+//! let config = "width=80 height=24".split_whitespace()
+//!     .map(|s| {
+//!         let mut parts = s.split('=');
+//!         let key = parts.next().unwrap();
+//!         parts.next().unwrap().parse::<u32>().map(|v| (key,v))
+//!     }).to_map_result().unwrap();
+//!
+//! assert_eq!(config["width"], 80);
+//! # }
+//! ```
+//!
+//! `Option<T>` gets the same treatment with `to_vec_option`, which
+//! returns `None` as soon as it meets a `None`:
+//!
+//! ```
+//! use to_vec::ToVecOption;
+//!
+//! let digits = "1a7".chars().map(|c| c.to_digit(16)).to_vec_option();
+//! assert_eq!(digits, Some(vec![1,10,7]));
+//!
+//! let digits = "1z7".chars().map(|c| c.to_digit(16)).to_vec_option();
+//! assert_eq!(digits, None);
+//! ```
+//!
+//! There are also `to_map_option`, `to_set_option`, `to_btree_map_option` and
+//! `to_btree_set_option`.
+//!
+//! ## Duplicate keys
+//!
+//! `to_map` quietly lets later entries overwrite earlier ones. If duplicate keys
+//! are a mistake, `try_to_map` (and `try_into_map` for owned pairs) fails on the
+//! first one, reporting the key and where it appeared:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{TryToMap,DuplicateKey};
+//! const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20),("hello",30)];
+//!
+//! let err = VALUES.iter().try_to_map().unwrap_err();
+//! assert_eq!(err, DuplicateKey{key: "hello", first: 0, second: 2});
+//! # }
+//! ```
+//!
+//! `try_to_map_all` and `try_into_map_all` report every duplicate instead.
+//!
+//! Often duplicates should be combined rather than rejected. `to_map_with`
+//! (and `into_map_with`) passes the key, the old value and the new value to
+//! a closure, whereas plain `to_map` always keeps the last value:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{ToMap,MergePolicy};
+//! const SCORES: &[(&str,i32)] = &[("bob",1),("alice",2),("bob",3)];
+//!
+//! assert_eq!(SCORES.iter().to_map()["bob"], 3);
+//!
+//! let totals = SCORES.iter().to_map_with(|_,a,b| a + b);
+//! assert_eq!(totals["bob"], 4);
+//!
+//! let first = SCORES.iter().to_map_policy(MergePolicy::KeepFirst).unwrap();
+//! assert_eq!(first["bob"], 1);
+//!
+//! assert!(SCORES.iter().to_map_policy(MergePolicy::Error).is_err());
+//! # }
+//! ```
+//!
+//! To build a lookup table from a slice of structs, `to_map_by` takes a
+//! closure that extracts the key and keeps the (cloned) item as the value.
+//! `into_map_by` moves owned items, and `try_to_map_by` rejects duplicate keys:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::ToMapBy;
+//!
+//! #[derive(Clone)]
+//! struct Person { name: &'static str, age: u32 }
+//!
+//! let people = [Person{name: "bob", age: 30}, Person{name: "alice", age: 30}];
+//! let by_name = people.iter().to_map_by(|p| p.name);
+//! assert_eq!(by_name["alice"].age, 30);
+//!
+//! assert!(people.iter().try_to_map_by(|p| p.age).is_err());
+//! # }
+//! ```
+//!
+//! ## Grouping and counting
+//!
+//! Grouping values by key is another common loop. `to_group_map` collects
+//! references to pairs into a `HashMap<K,Vec<V>>`, and `to_group_map_by`
+//! groups the items themselves using a key function:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{ToGroupMap,ToGroupMapBy};
+//! const PETS: &[(&str,&str)] = &[("dog","rex"),("cat","tom"),("dog","fido")];
+//!
+//! let pets = PETS.iter().to_group_map();
+//! assert_eq!(pets["dog"], &["rex","fido"]);
+//!
+//! let by_len = ["one","two","three"].iter().to_group_btree_map_by(|s| s.len());
+//! assert_eq!(by_len[&3], &["one","two"]);
+//! # }
+//! ```
+//!
+//! There are `BTreeMap` versions, versions which collect the groups into sets,
+//! and `into_group_map` and `into_group_map_by` for owned values.
+//!
+//! A special case of grouping is counting. `to_counts` builds a frequency
+//! map, and `most_common` picks out the largest counts:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{IntoCounts,MostCommon};
+//!
+//! let counts = "the cat saw the dog".split_whitespace().into_counts();
+//! assert_eq!(counts["the"], 2);
+//! assert_eq!(counts.most_common(1), &[(&"the",2)]);
+//! # }
+//! ```
+//!
+//! ## Shaping vectors
+//!
+//! A `Vec` often needs sorting straight away. `to_sorted_vec`,
+//! `to_sorted_vec_by_key`, `to_sorted_dedup_vec` and `to_unstable_sorted_vec`
+//! do that in one step. Their `_cloned` versions work on iterators of
+//! references, cloning like `to_set` does:
+//!
+//! ```
+//! use to_vec::{ToSortedVec,ToSortedVecCloned};
+//!
+//! let v = "one two three".split_whitespace().to_sorted_vec();
+//! assert_eq!(v, &["one","three","two"]);
+//!
+//! let v = [3,1,3,2].iter().to_sorted_dedup_vec_cloned();
+//! assert_eq!(v, &[1,2,3]);
+//! ```
+//!
+//! `to_set` forgets the original order. `to_unique_vec` removes duplicates
+//! but keeps the first occurrence of each value where it was. As with
+//! sorting, `to_unique_vec_cloned` works on references:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::{ToUniqueVec,ToUniqueVecCloned};
+//!
+//! let args = ["-v","--color","-v","-q"].iter().to_unique_vec_cloned();
+//! assert_eq!(args, &["-v","--color","-q"]);
+//!
+//! let words = "a b a".split_whitespace().to_unique_vec();
+//! assert_eq!(words, &["a","b"]);
+//! # }
+//! ```
+//!
+//! There are also `to_unique_vec_by_key` and `to_unique_vec_by_key_cloned`.
+//!
+//! `to_vec_partition` splits any iterator in two without needing a type
+//! annotation, and `to_vec_partition_results` (on `ToVecResult`) is the
+//! same as `to_vec_and_errors`:
+//!
+//! ```
+//! use to_vec::ToVec;
+//!
+//! let (even,odd) = (1..6).to_vec_partition(|i| i % 2 == 0);
+//! assert_eq!(even, &[2,4]);
+//! assert_eq!(odd, &[1,3,5]);
+//! ```
+//!
+//! `to_vec_unzip` splits pairs into two vectors, and `to_vec_unzip3` splits
+//! triples into three. Like `to_map`, they accept references and clone them:
+//!
+//! ```
+//! use to_vec::ToVecUnzip;
+//! const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+//!
+//! let (names,values) = VALUES.iter().to_vec_unzip();
+//! assert_eq!(names, &["hello","dolly"]);
+//! assert_eq!(values, &[10,20]);
+//! ```
+//!
+//! `to_map_unzip` does the same for maps, splitting `(key,(a,b))` items into
+//! two maps with the same keys.
+//!
+//! ## Other containers
+//!
+//! `collect` can also make a `String`, which `to_string_concat` does for
+//! iterators of `char` and string slices. For anything that implements
+//! `Display`, `to_string_joined` writes the values into one string with
+//! a separator:
+//!
+//! ```
+//! use to_vec::{ToStringJoined,ToStringConcat};
+//!
+//! assert_eq!([1,2,3].iter().to_string_joined(", "), "1, 2, 3");
+//! assert_eq!("hello".chars().rev().to_string_concat(), "olleh");
+//! ```
+//!
+//! For work queues there is `to_deque`, which makes a `VecDeque`, and
+//! `to_heap`, which makes a `BinaryHeap`. Like `to_vec` they take values;
+//! `to_deque_cloned` and `to_heap_cloned` take references, and
+//! `to_deque_result` and `to_heap_result` stop at the first error.
+//!
+//! ```
+//! use to_vec::ToHeapCloned;
+//!
+//! let mut heap = [3,1,4].iter().to_heap_cloned();
+//! assert_eq!(heap.pop(), Some(4));
+//! ```
+//!
+//! Immutable tables are often better as slices. `to_boxed_slice`,
+//! `to_rc_slice` and `to_arc_slice` (and their `_result` versions) collect
+//! straight into `Box<[T]>`, `Rc<[T]>` and `Arc<[T]>`, and `to_arc_str`
+//! and friends do the same for strings:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use to_vec::{ToSlice,ToSharedStr};
+//!
+//! let table: Arc<[u32]> = (1..4).map(|i| i * i).to_arc_slice();
+//! assert_eq!(&*table, &[1,4,9]);
+//!
+//! let name: Arc<str> = "hello".chars().rev().to_arc_str();
+//! assert_eq!(&*name, "olleh");
+//! ```
+//!
+//! Fixed-width records fit naturally into arrays. `to_array` needs exactly
+//! `N` items, and says whether there were too few or too many. The array
+//! is filled directly, without going through a `Vec`:
+//!
+//! ```
+//! use to_vec::{ToArray,ToArrayResult,ArrayLengthError};
+//!
+//! let rgb = "255 128 0".split_whitespace()
+//!     .map(|s| s.parse::<u8>().unwrap()).to_array::<3>().unwrap();
+//! assert_eq!(rgb, [255,128,0]);
+//!
+//! let err = (0..2).to_array::<3>().unwrap_err();
+//! assert_eq!(err, ArrayLengthError::TooFew{expected: 3, found: 2});
+//!
+//! let ip = "192.168.0.1".split('.').map(|s| s.parse::<u8>()).to_array_result::<4>();
+//! assert_eq!(ip.unwrap(), [192,168,0,1]);
+//! ```
+//!
+//! ## Capacity and hashers
+//!
+//! `collect` can only size its result from the iterator's `size_hint`, which
+//! is no help after a `filter`. If you know better, say so with
+//! `to_vec_with_capacity` (or `to_map_with_capacity` and `to_set_with_capacity`).
+//! For an `ExactSizeIterator`, `to_vec_exact` allocates exactly once:
+//!
+//! ```
+//! use to_vec::{ToVec,ToVecExact};
+//!
+//! let evens = (0..100).filter(|i| i % 2 == 0).to_vec_with_capacity(50);
+//! assert!(evens.capacity() >= 50);
+//!
+//! let squares = [1,2,3].iter().map(|i| i * i).to_vec_exact();
+//! assert_eq!(squares, &[1,4,9]);
+//! ```
+//!
+//! All the `HashMap` and `HashSet` collectors use the default hasher.
+//! `to_map_with_hasher` and `to_set_with_hasher` (and their `into_` versions)
+//! take a `BuildHasher`, just like `HashMap::with_hasher`:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use to_vec::ToSet;
+//! use std::collections::hash_map::RandomState;
+//!
+//! let set = ["one","two"].iter().to_set_with_hasher(RandomState::new());
+//! assert!(set.contains("two"));
+//! # }
+//! ```
+//!
+//! ## Limits and running out of memory
+//!
+//! Collecting a huge iterator will abort the process if memory runs out.
+//! `try_alloc_to_vec`, `try_alloc_to_set` and `try_alloc_to_map` grow their
+//! collections with `try_reserve`, and return an `AllocError` instead:
+//!
+//! ```
+//! use to_vec::TryAllocToVec;
+//!
+//! let v = (0..10).try_alloc_to_vec().unwrap();
+//! assert_eq!(v.len(), 10);
+//! ```
+//!
+//! When the iterator comes from outside, it's wise to put a limit on it.
+//! `to_vec_bounded` (and `to_map_bounded` and `to_set_bounded`) fail
+//! with `TooMany` if there are more than `max` items, which keeps what
+//! was collected:
+//!
+//! ```
+//! use to_vec::ToVecBounded;
+//!
+//! let err = (0..).to_vec_bounded(3).unwrap_err();
+//! assert_eq!(err.partial, &[0,1,2]);
+//! ```
+//!
+//! ## rayon
+//!
+//! With the `rayon` feature, `ParToVec`, `ParToVecResult`, `ParToMap` and
+//! `ParToSet` provide `to_vec`, `to_vec_result`, `to_map` and `to_set`
+//! for rayon's parallel iterators.
+//!
+//! ## futures
+//!
+//! With the `futures` feature, `StreamToVec`, `StreamToVecResult`, `StreamToMap`
+//! and `StreamToSet` provide the same methods for `futures::Stream`. They return
+//! futures, so you write `stream.to_vec().await`.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is on by default. Without it the crate only needs
+//! `alloc`, and provides the `Vec` and `BTreeMap`/`BTreeSet` collectors;
+//! everything that produces a `HashMap` or `HashSet` needs `std`.
 
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg_attr(test, macro_use)]
+extern crate alloc;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "futures")]
+extern crate futures;
+
+use alloc::vec::Vec;
+use alloc::collections::{BTreeMap,BTreeSet};
+#[cfg(feature = "std")]
 use std::collections::{HashMap,HashSet};
-use std::iter::FromIterator;
-use std::cmp::Eq;
-use std::hash::Hash;
-use std::result::Result;
+#[cfg(feature = "std")]
+use std::collections::hash_map::Entry;
+#[cfg(feature = "std")]
+use core::hash::{Hash,BuildHasher};
+use core::iter::FromIterator;
+use core::result::Result;
+
+mod error;
+pub use error::{DuplicateKey,AllocError,TooMany,ArrayLengthError,ArrayResultError};
+
+mod group;
+pub use group::{ToGroupMap,IntoGroupMap,ToGroupMapBy,IntoGroupMapBy};
+
+mod count;
+pub use count::{ToCounts,IntoCounts,MostCommon};
+
+mod reserve;
+pub use reserve::TryAllocToVec;
+#[cfg(feature = "std")]
+pub use reserve::{TryAllocToSet,TryAllocToMap};
+
+mod bounded;
+pub use bounded::ToVecBounded;
+#[cfg(feature = "std")]
+pub use bounded::{ToMapBounded,ToSetBounded};
+
+mod join;
+pub use join::{ToStringJoined,ToStringConcat};
+
+mod sorted;
+pub use sorted::{ToSortedVec,ToSortedVecCloned};
+
+mod unzip;
+pub use unzip::{ToVecUnzip,ToVecUnzip3,UnzipPair,UnzipTriple};
+#[cfg(feature = "std")]
+pub use unzip::ToMapUnzip;
+
+mod queue;
+pub use queue::{ToDeque,ToDequeCloned,ToDequeResult,ToHeap,ToHeapCloned,ToHeapResult};
+
+mod slice;
+pub use slice::{ToSlice,ToSliceResult,ToSharedStr,ToSharedStrResult};
+
+mod array;
+pub use array::{ToArray,ToArrayResult};
+
+#[cfg(feature = "std")]
+mod unique;
+#[cfg(feature = "std")]
+pub use unique::{ToUniqueVec,ToUniqueVecCloned};
+
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+pub use par::{ParToVec,ParToVecResult,ParToMap,ParToSet};
+
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::{StreamToVec,StreamToVecResult,StreamToMap,StreamToSet,ToMapFuture,ToSetFuture};
 
 /// to_vec() method on iterators
 pub trait ToVec<T> {
     /// a more definite alternative to `collect`
     /// which collects an iterator's values into a Vec
     fn to_vec(self) -> Vec<T>;
+
+    /// collect into a Vec which starts with room for `n` values
+    fn to_vec_with_capacity(self, n: usize) -> Vec<T>;
+
+    /// split into the values for which `pred` is true,
+    /// and those for which it is false
+    fn to_vec_partition<F>(self, pred: F) -> (Vec<T>,Vec<T>)
+    where F: FnMut(&T) -> bool;
+}
+
+/// to_vec_exact() method on iterators which know their length
+pub trait ToVecExact<T> {
+    /// collect into a Vec allocated once with the iterator's reported length.
+    /// Panics if the iterator yields a different number of values.
+    fn to_vec_exact(self) -> Vec<T>;
 }
 
 /// to_vec_result() method on iterators
@@ -62,18 +537,262 @@ pub trait ToVecResult<T,E> {
     /// this collects an iterator of `Result<T,E>`
     /// into a result of `Result<Vec<T>,E>`
     fn to_vec_result(self) -> Result<Vec<T>,E>;
+
+    /// split into the successful values and the errors
+    fn to_vec_partition_results(self) -> (Vec<T>,Vec<E>);
+}
+
+/// to_vec_all_errors() and friends on iterators of results
+pub trait ToVecAllErrors<T,E> {
+    /// like `to_vec_result`, but keeps going after an error
+    /// and returns _all_ the errors encountered
+    fn to_vec_all_errors(self) -> Result<Vec<T>,Vec<E>>;
+
+    /// like `to_vec_all_errors`, but each error is paired
+    /// with the index of the offending item
+    fn to_vec_indexed_errors(self) -> Result<Vec<T>,Vec<(usize,E)>>;
+
+    /// split into the successful values and the errors
+    fn to_vec_and_errors(self) -> (Vec<T>,Vec<E>);
+
+    /// split into the successful values and the errors,
+    /// each paired with the index of the offending item
+    fn to_vec_and_indexed_errors(self) -> (Vec<T>,Vec<(usize,E)>);
+}
+
+/// to_vec_option() method on iterators
+pub trait ToVecOption<T> {
+    /// this collects an iterator of `Option<T>`
+    /// into an option of `Option<Vec<T>>`
+    fn to_vec_option(self) -> Option<Vec<T>>;
+}
+
+/// to_map_option() method on iterators
+#[cfg(feature = "std")]
+pub trait ToMapOption<K,V> {
+    /// this collects an iterator of `Option<(K,V)>`
+    /// into an option of `Option<HashMap<K,V>>`
+    fn to_map_option(self) -> Option<HashMap<K,V>>;
+}
+
+/// to_set_option() method on iterators
+#[cfg(feature = "std")]
+pub trait ToSetOption<K> {
+    /// this collects an iterator of `Option<K>`
+    /// into an option of `Option<HashSet<K>>`
+    fn to_set_option(self) -> Option<HashSet<K>>;
+}
+
+/// to_btree_map_option() method on iterators
+pub trait ToBTreeMapOption<K,V> {
+    /// this collects an iterator of `Option<(K,V)>`
+    /// into an option of `Option<BTreeMap<K,V>>`
+    fn to_btree_map_option(self) -> Option<BTreeMap<K,V>>;
+}
+
+/// to_btree_set_option() method on iterators
+pub trait ToBTreeSetOption<K> {
+    /// this collects an iterator of `Option<K>`
+    /// into an option of `Option<BTreeSet<K>>`
+    fn to_btree_set_option(self) -> Option<BTreeSet<K>>;
+}
+
+/// to_map_result() method on iterators
+#[cfg(feature = "std")]
+pub trait ToMapResult<K,V,E> {
+    /// this collects an iterator of `Result<(K,V),E>`
+    /// into a result of `Result<HashMap<K,V>,E>`
+    fn to_map_result(self) -> Result<HashMap<K,V>,E>;
+}
+
+/// to_set_result() method on iterators
+#[cfg(feature = "std")]
+pub trait ToSetResult<K,E> {
+    /// this collects an iterator of `Result<K,E>`
+    /// into a result of `Result<HashSet<K>,E>`
+    fn to_set_result(self) -> Result<HashSet<K>,E>;
+}
+
+/// to_btree_map_result() method on iterators
+pub trait ToBTreeMapResult<K,V,E> {
+    /// this collects an iterator of `Result<(K,V),E>`
+    /// into a result of `Result<BTreeMap<K,V>,E>`
+    fn to_btree_map_result(self) -> Result<BTreeMap<K,V>,E>;
+}
+
+/// to_btree_set_result() method on iterators
+pub trait ToBTreeSetResult<K,E> {
+    /// this collects an iterator of `Result<K,E>`
+    /// into a result of `Result<BTreeSet<K>,E>`
+    fn to_btree_set_result(self) -> Result<BTreeSet<K>,E>;
+}
+
+/// what to do when a key turns up more than once
+#[cfg(feature = "std")]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum MergePolicy {
+    /// keep the value that came first
+    KeepFirst,
+    /// keep the value that came last (what `collect` does)
+    KeepLast,
+    /// fail with `DuplicateKey`
+    Error,
 }
 
 /// to_map() method on iterators of references
+#[cfg(feature = "std")]
 pub trait ToMap<K,V> {
     /// collect references into a HashMap by cloning
     fn to_map(self) -> HashMap<K,V>;
+
+    /// collect references into a HashMap by cloning,
+    /// combining values for duplicate keys with `merge(key,old,new)`
+    fn to_map_with<F>(self, merge: F) -> HashMap<K,V>
+    where F: FnMut(&K,V,V) -> V;
+
+    /// collect references into a HashMap by cloning,
+    /// handling duplicate keys according to `policy`
+    fn to_map_policy(self, policy: MergePolicy) -> Result<HashMap<K,V>,DuplicateKey<K>>;
+
+    /// collect references into a HashMap by cloning,
+    /// using the given hasher
+    fn to_map_with_hasher<S>(self, hasher: S) -> HashMap<K,V,S>
+    where S: BuildHasher;
+
+    /// collect references into a HashMap by cloning,
+    /// which starts with room for `n` entries
+    fn to_map_with_capacity(self, n: usize) -> HashMap<K,V>;
+}
+
+/// to_map_by() methods on iterators of references
+#[cfg(feature = "std")]
+pub trait ToMapBy<T> {
+    /// collect cloned items into a HashMap using `key(&item)`
+    fn to_map_by<K,F>(self, key: F) -> HashMap<K,T>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// collect cloned items into a HashMap using `key(&item)`,
+    /// failing on the first duplicate key
+    fn try_to_map_by<K,F>(self, key: F) -> Result<HashMap<K,T>,DuplicateKey<K>>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+}
+
+/// into_map_by() methods on iterators of owned values
+#[cfg(feature = "std")]
+pub trait IntoMapBy<T> {
+    /// collect items into a HashMap using `key(&item)`
+    fn into_map_by<K,F>(self, key: F) -> HashMap<K,T>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+
+    /// collect items into a HashMap using `key(&item)`,
+    /// failing on the first duplicate key
+    fn try_into_map_by<K,F>(self, key: F) -> Result<HashMap<K,T>,DuplicateKey<K>>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
 }
 
 /// to_set() method on iterators of references
+#[cfg(feature = "std")]
 pub trait ToSet<K> {
     /// collect values into a HashSet by cloning
     fn to_set(self) -> HashSet<K>;
+
+    /// collect values into a HashSet by cloning,
+    /// using the given hasher
+    fn to_set_with_hasher<S>(self, hasher: S) -> HashSet<K,S>
+    where S: BuildHasher;
+
+    /// collect values into a HashSet by cloning,
+    /// which starts with room for `n` values
+    fn to_set_with_capacity(self, n: usize) -> HashSet<K>;
+}
+
+/// try_to_map() method on iterators of references
+#[cfg(feature = "std")]
+pub trait TryToMap<K,V> {
+    /// collect references into a HashMap by cloning,
+    /// failing on the first duplicate key
+    fn try_to_map(self) -> Result<HashMap<K,V>,DuplicateKey<K>>;
+
+    /// collect references into a HashMap by cloning,
+    /// failing with all the duplicate keys found
+    fn try_to_map_all(self) -> Result<HashMap<K,V>,Vec<DuplicateKey<K>>>;
+}
+
+/// try_into_map() method on iterators of owned pairs
+#[cfg(feature = "std")]
+pub trait TryIntoMap<K,V> {
+    /// collect pairs into a HashMap without cloning,
+    /// failing on the first duplicate key
+    fn try_into_map(self) -> Result<HashMap<K,V>,DuplicateKey<K>>;
+
+    /// collect pairs into a HashMap without cloning,
+    /// failing with all the duplicate keys found
+    fn try_into_map_all(self) -> Result<HashMap<K,V>,Vec<DuplicateKey<K>>>;
+}
+
+/// into_map() method on iterators of owned pairs
+#[cfg(feature = "std")]
+pub trait IntoMap<K,V> {
+    /// collect pairs into a HashMap without cloning
+    fn into_map(self) -> HashMap<K,V>;
+
+    /// collect pairs into a HashMap without cloning,
+    /// combining values for duplicate keys with `merge(key,old,new)`
+    fn into_map_with<F>(self, merge: F) -> HashMap<K,V>
+    where F: FnMut(&K,V,V) -> V;
+
+    /// collect pairs into a HashMap without cloning,
+    /// handling duplicate keys according to `policy`
+    fn into_map_policy(self, policy: MergePolicy) -> Result<HashMap<K,V>,DuplicateKey<K>>;
+
+    /// collect pairs into a HashMap without cloning,
+    /// using the given hasher
+    fn into_map_with_hasher<S>(self, hasher: S) -> HashMap<K,V,S>
+    where S: BuildHasher;
+
+    /// collect pairs into a HashMap without cloning,
+    /// which starts with room for `n` entries
+    fn into_map_with_capacity(self, n: usize) -> HashMap<K,V>;
+}
+
+/// into_set() method on iterators of owned values
+#[cfg(feature = "std")]
+pub trait IntoSet<K> {
+    /// collect values into a HashSet without cloning
+    fn into_set(self) -> HashSet<K>;
+
+    /// collect values into a HashSet without cloning,
+    /// using the given hasher
+    fn into_set_with_hasher<S>(self, hasher: S) -> HashSet<K,S>
+    where S: BuildHasher;
+
+    /// collect values into a HashSet without cloning,
+    /// which starts with room for `n` values
+    fn into_set_with_capacity(self, n: usize) -> HashSet<K>;
+}
+
+/// to_btree_map() method on iterators of references
+pub trait ToBTreeMap<K,V> {
+    /// collect references into a BTreeMap by cloning
+    fn to_btree_map(self) -> BTreeMap<K,V>;
+}
+
+/// to_btree_set() method on iterators of references
+pub trait ToBTreeSet<K> {
+    /// collect values into a BTreeSet by cloning
+    fn to_btree_set(self) -> BTreeSet<K>;
+}
+
+/// into_btree_map() method on iterators of owned pairs
+pub trait IntoBTreeMap<K,V> {
+    /// collect pairs into a BTreeMap without cloning
+    fn into_btree_map(self) -> BTreeMap<K,V>;
+}
+
+/// into_btree_set() method on iterators of owned values
+pub trait IntoBTreeSet<K> {
+    /// collect values into a BTreeSet without cloning
+    fn into_btree_set(self) -> BTreeSet<K>;
 }
 
 impl <T,I> ToVec<T> for I
@@ -81,6 +800,27 @@ where I: Iterator<Item=T> {
     fn to_vec(self) -> Vec<T> {
         FromIterator::from_iter(self)
     }
+
+    fn to_vec_with_capacity(self, n: usize) -> Vec<T> {
+        let mut v = Vec::with_capacity(n);
+        v.extend(self);
+        v
+    }
+
+    fn to_vec_partition<F>(self, pred: F) -> (Vec<T>,Vec<T>)
+    where F: FnMut(&T) -> bool {
+        self.partition(pred)
+    }
+}
+
+impl <T,I> ToVecExact<T> for I
+where I: ExactSizeIterator<Item=T> {
+    fn to_vec_exact(self) -> Vec<T> {
+        let n = self.len();
+        let v = self.to_vec_with_capacity(n);
+        assert_eq!(v.len(), n, "ExactSizeIterator reported the wrong length");
+        v
+    }
 }
 
 impl <T,E,I> ToVecResult<T,E> for I
@@ -88,27 +828,339 @@ where I: Iterator<Item=Result<T,E>> {
     fn to_vec_result(self) -> Result<Vec<T>,E> {
         FromIterator::from_iter(self)
     }
+
+    fn to_vec_partition_results(self) -> (Vec<T>,Vec<E>) {
+        self.to_vec_and_errors()
+    }
+}
+
+impl <T,E,I> ToVecAllErrors<T,E> for I
+where I: Iterator<Item=Result<T,E>> {
+    fn to_vec_all_errors(self) -> Result<Vec<T>,Vec<E>> {
+        let (values, errors) = self.to_vec_and_errors();
+        if errors.is_empty() { Ok(values) } else { Err(errors) }
+    }
+
+    fn to_vec_indexed_errors(self) -> Result<Vec<T>,Vec<(usize,E)>> {
+        let (values, errors) = self.to_vec_and_indexed_errors();
+        if errors.is_empty() { Ok(values) } else { Err(errors) }
+    }
+
+    fn to_vec_and_errors(self) -> (Vec<T>,Vec<E>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for res in self {
+            match res {
+                Ok(v) => values.push(v),
+                Err(e) => errors.push(e),
+            }
+        }
+        (values, errors)
+    }
+
+    fn to_vec_and_indexed_errors(self) -> (Vec<T>,Vec<(usize,E)>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for (i,res) in self.enumerate() {
+            match res {
+                Ok(v) => values.push(v),
+                Err(e) => errors.push((i,e)),
+            }
+        }
+        (values, errors)
+    }
+}
+
+impl <T,I> ToVecOption<T> for I
+where I: Iterator<Item=Option<T>> {
+    fn to_vec_option(self) -> Option<Vec<T>> {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,V,I> ToMapOption<K,V> for I
+where K: Eq + Hash, I: Iterator<Item=Option<(K,V)>> {
+    fn to_map_option(self) -> Option<HashMap<K,V>> {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,I> ToSetOption<K> for I
+where K: Eq + Hash, I: Iterator<Item=Option<K>> {
+    fn to_set_option(self) -> Option<HashSet<K>> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <K,V,I> ToBTreeMapOption<K,V> for I
+where K: Ord, I: Iterator<Item=Option<(K,V)>> {
+    fn to_btree_map_option(self) -> Option<BTreeMap<K,V>> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <K,I> ToBTreeSetOption<K> for I
+where K: Ord, I: Iterator<Item=Option<K>> {
+    fn to_btree_set_option(self) -> Option<BTreeSet<K>> {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,V,E,I> ToMapResult<K,V,E> for I
+where K: Eq + Hash, I: Iterator<Item=Result<(K,V),E>> {
+    fn to_map_result(self) -> Result<HashMap<K,V>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,E,I> ToSetResult<K,E> for I
+where K: Eq + Hash, I: Iterator<Item=Result<K,E>> {
+    fn to_set_result(self) -> Result<HashSet<K>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <K,V,E,I> ToBTreeMapResult<K,V,E> for I
+where K: Ord, I: Iterator<Item=Result<(K,V),E>> {
+    fn to_btree_map_result(self) -> Result<BTreeMap<K,V>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <K,E,I> ToBTreeSetResult<K,E> for I
+where K: Ord, I: Iterator<Item=Result<K,E>> {
+    fn to_btree_set_result(self) -> Result<BTreeSet<K>,E> {
+        FromIterator::from_iter(self)
+    }
 }
 
+#[cfg(feature = "std")]
 impl <'a, K,V,I> ToMap<K,V> for I
 where K: Eq + Hash + Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)>   {
     fn to_map(self) -> HashMap<K,V> {
         FromIterator::from_iter(self.cloned())
     }
+
+    fn to_map_with<F>(self, merge: F) -> HashMap<K,V>
+    where F: FnMut(&K,V,V) -> V {
+        self.cloned().into_map_with(merge)
+    }
+
+    fn to_map_policy(self, policy: MergePolicy) -> Result<HashMap<K,V>,DuplicateKey<K>> {
+        self.cloned().into_map_policy(policy)
+    }
+
+    fn to_map_with_hasher<S>(self, hasher: S) -> HashMap<K,V,S>
+    where S: BuildHasher {
+        self.cloned().into_map_with_hasher(hasher)
+    }
+
+    fn to_map_with_capacity(self, n: usize) -> HashMap<K,V> {
+        self.cloned().into_map_with_capacity(n)
+    }
+}
+
+
+#[cfg(feature = "std")]
+impl <'a, T,I> ToMapBy<T> for I
+where T: Clone +'a, I: Iterator<Item=&'a T> {
+    fn to_map_by<K,F>(self, key: F) -> HashMap<K,T>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        self.cloned().into_map_by(key)
+    }
+
+    fn try_to_map_by<K,F>(self, key: F) -> Result<HashMap<K,T>,DuplicateKey<K>>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        self.cloned().try_into_map_by(key)
+    }
 }
 
+#[cfg(feature = "std")]
+impl <T,I> IntoMapBy<T> for I
+where I: Iterator<Item=T> {
+    fn into_map_by<K,F>(self, mut key: F) -> HashMap<K,T>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        self.map(|t| (key(&t),t)).collect()
+    }
+
+    fn try_into_map_by<K,F>(self, mut key: F) -> Result<HashMap<K,T>,DuplicateKey<K>>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        self.map(|t| (key(&t),t)).try_into_map()
+    }
+}
 
+#[cfg(feature = "std")]
 impl <'a, K,I> ToSet<K> for I
 where K: Eq + Hash + Clone + 'a, I: Iterator<Item=&'a K>   {
     fn to_set(self) -> HashSet<K> {
         FromIterator::from_iter(self.cloned())
     }
+
+    fn to_set_with_hasher<S>(self, hasher: S) -> HashSet<K,S>
+    where S: BuildHasher {
+        self.cloned().into_set_with_hasher(hasher)
+    }
+
+    fn to_set_with_capacity(self, n: usize) -> HashSet<K> {
+        self.cloned().into_set_with_capacity(n)
+    }
+}
+
+// collect pairs into a map, recording where each key was first seen.
+// If `all` is false we give up at the first duplicate.
+#[cfg(feature = "std")]
+fn unique_map<K,V,I>(iter: I, all: bool) -> Result<HashMap<K,V>,Vec<DuplicateKey<K>>>
+where K: Eq + Hash, I: Iterator<Item=(K,V)> {
+    let mut map: HashMap<K,(usize,V)> = HashMap::with_capacity(iter.size_hint().0);
+    let mut dups = Vec::new();
+    for (i,(k,v)) in iter.enumerate() {
+        if let Some(&(first,_)) = map.get(&k) {
+            dups.push(DuplicateKey{key: k, first, second: i});
+            if ! all {
+                break;
+            }
+        } else {
+            map.insert(k,(i,v));
+        }
+    }
+    if dups.is_empty() {
+        Ok(map.into_iter().map(|(k,(_,v))| (k,v)).collect())
+    } else {
+        Err(dups)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K,V,I> TryToMap<K,V> for I
+where K: Eq + Hash + Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)>   {
+    fn try_to_map(self) -> Result<HashMap<K,V>,DuplicateKey<K>> {
+        self.cloned().try_into_map()
+    }
+
+    fn try_to_map_all(self) -> Result<HashMap<K,V>,Vec<DuplicateKey<K>>> {
+        self.cloned().try_into_map_all()
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,V,I> TryIntoMap<K,V> for I
+where K: Eq + Hash, I: Iterator<Item=(K,V)>   {
+    fn try_into_map(self) -> Result<HashMap<K,V>,DuplicateKey<K>> {
+        unique_map(self,false).map_err(|mut dups| dups.remove(0))
+    }
+
+    fn try_into_map_all(self) -> Result<HashMap<K,V>,Vec<DuplicateKey<K>>> {
+        unique_map(self,true)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,V,I> IntoMap<K,V> for I
+where K: Eq + Hash, I: Iterator<Item=(K,V)>   {
+    fn into_map(self) -> HashMap<K,V> {
+        FromIterator::from_iter(self)
+    }
+
+    fn into_map_with<F>(self, mut merge: F) -> HashMap<K,V>
+    where F: FnMut(&K,V,V) -> V {
+        let mut map = HashMap::with_capacity(self.size_hint().0);
+        for (k,v) in self {
+            match map.entry(k) {
+                Entry::Occupied(e) => {
+                    let (k,old) = e.remove_entry();
+                    let v = merge(&k,old,v);
+                    map.insert(k,v);
+                },
+                Entry::Vacant(e) => {
+                    e.insert(v);
+                }
+            }
+        }
+        map
+    }
+
+    fn into_map_policy(self, policy: MergePolicy) -> Result<HashMap<K,V>,DuplicateKey<K>> {
+        match policy {
+            MergePolicy::KeepFirst => Ok(self.into_map_with(|_,old,_| old)),
+            MergePolicy::KeepLast => Ok(self.into_map()),
+            MergePolicy::Error => self.try_into_map(),
+        }
+    }
+
+    fn into_map_with_hasher<S>(self, hasher: S) -> HashMap<K,V,S>
+    where S: BuildHasher {
+        let mut map = HashMap::with_hasher(hasher);
+        map.extend(self);
+        map
+    }
+
+    fn into_map_with_capacity(self, n: usize) -> HashMap<K,V> {
+        let mut map = HashMap::with_capacity(n);
+        map.extend(self);
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+impl <K,I> IntoSet<K> for I
+where K: Eq + Hash, I: Iterator<Item=K>   {
+    fn into_set(self) -> HashSet<K> {
+        FromIterator::from_iter(self)
+    }
+
+    fn into_set_with_hasher<S>(self, hasher: S) -> HashSet<K,S>
+    where S: BuildHasher {
+        let mut set = HashSet::with_hasher(hasher);
+        set.extend(self);
+        set
+    }
+
+    fn into_set_with_capacity(self, n: usize) -> HashSet<K> {
+        let mut set = HashSet::with_capacity(n);
+        set.extend(self);
+        set
+    }
+}
+
+impl <'a, K,V,I> ToBTreeMap<K,V> for I
+where K: Ord + Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)>   {
+    fn to_btree_map(self) -> BTreeMap<K,V> {
+        FromIterator::from_iter(self.cloned())
+    }
+}
+
+impl <'a, K,I> ToBTreeSet<K> for I
+where K: Ord + Clone + 'a, I: Iterator<Item=&'a K>   {
+    fn to_btree_set(self) -> BTreeSet<K> {
+        FromIterator::from_iter(self.cloned())
+    }
+}
+
+impl <K,V,I> IntoBTreeMap<K,V> for I
+where K: Ord, I: Iterator<Item=(K,V)>   {
+    fn into_btree_map(self) -> BTreeMap<K,V> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <K,I> IntoBTreeSet<K> for I
+where K: Ord, I: Iterator<Item=K>   {
+    fn into_btree_set(self) -> BTreeSet<K> {
+        FromIterator::from_iter(self)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use alloc::string::String;
 
     #[test]
     fn test_to_vec() {
@@ -116,6 +1168,82 @@ mod tests {
         assert_eq!(v,&["one","two","three"]);
     }
 
+    #[test]
+    fn test_to_vec_with_capacity() {
+        let v = (0..100).filter(|i| i % 3 == 0).to_vec_with_capacity(34);
+        assert_eq!(v.len(),34);
+        assert_eq!(v.capacity(),34);
+
+        let v = (0..10).to_vec_with_capacity(0);
+        assert_eq!(v.len(),10);
+    }
+
+    #[test]
+    fn test_to_vec_exact() {
+        let v = [1,2,3].iter().map(|i| i * 10).to_vec_exact();
+        assert_eq!(v,&[10,20,30]);
+        assert_eq!(v.capacity(),3);
+    }
+
+    // claims to have more items than it does
+    struct Liar(usize);
+
+    impl Iterator for Liar {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            if self.0 == 0 { None } else { self.0 -= 1; Some(self.0) }
+        }
+    }
+
+    impl ExactSizeIterator for Liar {
+        fn len(&self) -> usize {
+            self.0 + 1
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong length")]
+    fn test_to_vec_exact_wrong_length() {
+        Liar(3).to_vec_exact();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_capacity() {
+        let map = VALUES.iter().to_map_with_capacity(10);
+        assert!(map.capacity() >= 10);
+        assert_eq!(map["dolly"],20);
+
+        let map = VALUES.iter().cloned().into_map_with_capacity(10);
+        assert!(map.capacity() >= 10);
+
+        let set = [10,5,2,5,10].iter().to_set_with_capacity(10);
+        assert!(set.capacity() >= 10);
+        assert_eq!(set.len(),3);
+
+        let set = vec![1,2].into_iter().into_set_with_capacity(10);
+        assert!(set.capacity() >= 10);
+    }
+
+    #[test]
+    fn test_to_vec_partition() {
+        let (even,odd) = (1..10).to_vec_partition(|i| i % 2 == 0);
+        assert_eq!(even,&[2,4,6,8]);
+        assert_eq!(odd,&[1,3,5,7,9]);
+
+        let (yes,no) = (0..0).to_vec_partition(|_| true);
+        assert!(yes.is_empty() && no.is_empty());
+    }
+
+    #[test]
+    fn test_to_vec_partition_results() {
+        let (numbers,errors) = "23E X FF00".split_whitespace()
+            .map(|s| u32::from_str_radix(s,16)).to_vec_partition_results();
+        assert_eq!(numbers,&[0x23E, 0xFF00]);
+        assert_eq!(errors.len(),1);
+    }
+
     #[test]
     fn test_to_vec_result() {
         let numbers = "23E 5F5 FF00".split_whitespace()
@@ -124,6 +1252,148 @@ mod tests {
         assert_eq!(numbers,&[0x23E, 0x5F5, 0xFF00]);
     }
 
+    #[test]
+    fn test_to_vec_all_errors() {
+        let parse = |s: &str| s.parse::<i32>().map_err(|_| s.to_string());
+
+        let res = "1 x 2 y".split_whitespace().map(parse).to_vec_all_errors();
+        assert_eq!(res,Err(vec!["x".to_string(),"y".to_string()]));
+
+        let res = "1 2".split_whitespace().map(parse).to_vec_all_errors();
+        assert_eq!(res,Ok(vec![1,2]));
+
+        let res = "1 x 2 y".split_whitespace().map(parse).to_vec_indexed_errors();
+        assert_eq!(res,Err(vec![(1,"x".to_string()),(3,"y".to_string())]));
+
+        let (values,errors) = "1 x 2".split_whitespace().map(parse).to_vec_and_errors();
+        assert_eq!(values,&[1,2]);
+        assert_eq!(errors,&["x"]);
+
+        let (values,errors) = "x 1 2".split_whitespace().map(parse).to_vec_and_indexed_errors();
+        assert_eq!(values,&[1,2]);
+        assert_eq!(errors,&[(0,"x".to_string())]);
+    }
+
+    #[test]
+    fn test_to_vec_option() {
+        let digits = "1a7".chars().map(|c| c.to_digit(16)).to_vec_option();
+        assert_eq!(digits,Some(vec![1,10,7]));
+
+        let mut seen = 0;
+        let digits = "1z7".chars().inspect(|_| seen += 1)
+            .map(|c| c.to_digit(16)).to_vec_option();
+        assert_eq!(digits,None);
+        assert_eq!(seen,2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_option() {
+        let map = VALUES.iter().map(|&(k,v)| Some((k,v))).to_map_option().unwrap();
+        assert_eq!(map["dolly"],20);
+
+        let map = VALUES.iter()
+            .map(|&(k,v)| if v > 10 {None} else {Some((k,v))})
+            .to_map_option();
+        assert_eq!(map,None);
+    }
+
+    #[test]
+    fn test_to_btree_map_option() {
+        let map = VALUES.iter().map(|&(k,v)| Some((k,v))).to_btree_map_option().unwrap();
+        assert_eq!(map.into_iter().to_vec(),&[("dolly",20),("hello",10)]);
+
+        let map = VALUES.iter()
+            .map(|&(k,v)| if v > 10 {None} else {Some((k,v))})
+            .to_btree_map_option();
+        assert_eq!(map,None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_set_option() {
+        let set = "abba".chars().map(|c| c.to_digit(16)).to_set_option().unwrap();
+        assert_eq!(set,[10,11].iter().to_set());
+
+        let set = "abxa".chars().map(|c| c.to_digit(16)).to_set_option();
+        assert_eq!(set,None);
+    }
+
+    #[test]
+    fn test_to_btree_set_option() {
+        let set = "baba".chars().map(|c| c.to_digit(16)).to_btree_set_option().unwrap();
+        assert_eq!(set.into_iter().to_vec(),&[10,11]);
+
+        let set = "abxa".chars().map(|c| c.to_digit(16)).to_btree_set_option();
+        assert_eq!(set,None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_result() {
+        let map = "a=1 b=2".split_whitespace()
+            .map(|s| s[2..].parse::<i32>().map(|v| (&s[0..1],v)))
+            .to_map_result().unwrap();
+        assert_eq!(map["b"],2);
+
+        // stops at the first error, and doesn't look any further
+        let mut seen = 0;
+        let res = "1 x 2 y".split_whitespace()
+            .inspect(|_| seen += 1)
+            .map(|s| s.parse::<i32>().map(|v| (v,v)))
+            .to_map_result();
+        assert!(res.is_err());
+        assert_eq!(seen,2);
+    }
+
+    #[test]
+    fn test_to_btree_map_result() {
+        let map = "b=2 a=1".split_whitespace()
+            .map(|s| s[2..].parse::<i32>().map(|v| (&s[0..1],v)))
+            .to_btree_map_result().unwrap();
+        assert_eq!(map.into_iter().to_vec(),&[("a",1),("b",2)]);
+
+        let mut seen = 0;
+        let res = "1 x 2 y".split_whitespace()
+            .inspect(|_| seen += 1)
+            .map(|s| s.parse::<i32>().map(|v| (v,v)))
+            .to_btree_map_result();
+        assert!(res.is_err());
+        assert_eq!(seen,2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_set_result() {
+        let set = "10 5 10".split_whitespace()
+            .map(|s| s.parse::<i32>()).to_set_result().unwrap();
+        assert_eq!(set.len(),2);
+
+        let mut seen = 0;
+        let res = "1 x 2 y".split_whitespace()
+            .inspect(|_| seen += 1)
+            .map(|s| s.parse::<i32>())
+            .to_set_result();
+        assert!(res.is_err());
+        assert_eq!(seen,2);
+    }
+
+    #[test]
+    fn test_to_btree_set_result() {
+        let set = "10 5 10".split_whitespace()
+            .map(|s| s.parse::<i32>()).to_btree_set_result().unwrap();
+        assert_eq!(set.into_iter().to_vec(),&[5,10]);
+
+        let mut seen = 0;
+        let res = "1 x 2 y".split_whitespace()
+            .inspect(|_| seen += 1)
+            .map(|s| s.parse::<i32>())
+            .to_btree_set_result();
+        assert!(res.is_err());
+        assert_eq!(seen,2);
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_to_set() {
         let set1 = [10,5,2,5,10].iter().to_set();
@@ -142,6 +1412,7 @@ mod tests {
 
     const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_to_map() {
 
@@ -152,5 +1423,197 @@ mod tests {
 
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_to_map() {
+        let map = VALUES.iter().try_to_map().unwrap();
+        assert_eq!(map,VALUES.iter().to_map());
+
+        const DUPS: &[(&str,i32)] = &[("a",1),("b",2),("a",3),("b",4),("a",5)];
+        let err = DUPS.iter().try_to_map().unwrap_err();
+        assert_eq!(err,DuplicateKey{key: "a", first: 0, second: 2});
+        assert_eq!(err.to_string(),"duplicate key \"a\" at positions 0 and 2");
+
+        let errs = DUPS.iter().try_to_map_all().unwrap_err();
+        assert_eq!(errs.iter().map(|d| (d.key,d.first,d.second)).to_vec(),
+            &[("a",0,2),("b",1,3),("a",0,4)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_into_map() {
+        let map = vec![Record{id: 1},Record{id: 2}].into_iter()
+            .map(|r| (r.id, r)).try_into_map().unwrap();
+        assert_eq!(map[&1],Record{id: 1});
+
+        let err = vec![(Record{id: 1},1),(Record{id: 1},2)].into_iter()
+            .try_into_map().unwrap_err();
+        assert_eq!(err.key,Record{id: 1});
+        assert_eq!((err.first,err.second),(0,1));
+    }
+
+    // a deliberately simple FNV-1a hasher, so results don't depend on a random seed
+    #[cfg(feature = "std")]
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    #[cfg(feature = "std")]
+    impl std::hash::Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for b in bytes {
+                self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Clone,Copy)]
+    struct FnvBuild(u64);
+
+    #[cfg(feature = "std")]
+    impl BuildHasher for FnvBuild {
+        type Hasher = Fnv;
+
+        fn build_hasher(&self) -> Fnv {
+            Fnv(self.0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_hasher() {
+        let seed = FnvBuild(0xcbf29ce484222325);
+
+        let map = VALUES.iter().to_map_with_hasher(seed);
+        assert_eq!(map.get("dolly"),Some(&20));
+
+        let map2 = VALUES.iter().cloned().into_map_with_hasher(seed);
+        assert_eq!(map.iter().to_vec(),map2.iter().to_vec());
+
+        let set = [10,5,2,5,10].iter().to_set_with_hasher(seed);
+        assert_eq!(set.len(),3);
+
+        let set2 = vec![10,5,2,5,10].into_iter().into_set_with_hasher(seed);
+        assert_eq!(set.iter().to_vec(),set2.iter().to_vec());
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Clone,Debug,PartialEq)]
+    struct Person {
+        id: String,
+        age: u32,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_by() {
+        let people = [
+            Person{id: "bob".into(), age: 30},
+            Person{id: "alice".into(), age: 30},
+        ];
+        let map = people.iter().to_map_by(|p| p.id.clone());
+        assert_eq!(map["alice"],people[1]);
+
+        let map = people.iter().try_to_map_by(|p| p.id.clone()).unwrap();
+        assert_eq!(map.len(),2);
+
+        let err = people.iter().try_to_map_by(|p| p.age).unwrap_err();
+        assert_eq!(err,DuplicateKey{key: 30, first: 0, second: 1});
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_map_by() {
+        let map = vec![Record{id: 1},Record{id: 2}].into_iter().into_map_by(|r| r.id);
+        assert_eq!(map[&2],Record{id: 2});
+
+        let map = vec![Record{id: 1},Record{id: 1}].into_iter().into_map_by(|r| r.id);
+        assert_eq!(map.len(),1);
+
+        let err = vec![Record{id: 1},Record{id: 1}].into_iter()
+            .try_into_map_by(|r| r.id).unwrap_err();
+        assert_eq!((err.key,err.first,err.second),(1,0,1));
+    }
+
+    #[cfg(feature = "std")]
+    const SCORES: &[(&str,i32)] = &[("bob",1),("alice",2),("bob",3)];
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_with() {
+        let map = SCORES.iter().to_map_with(|_,a,b| a + b);
+        assert_eq!(map["bob"],4);
+        assert_eq!(map["alice"],2);
+
+        let map = SCORES.iter().cloned().into_map_with(|k,a,b| if *k == "bob" {a} else {b});
+        assert_eq!(map["bob"],1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_policy() {
+        let map = SCORES.iter().to_map_policy(MergePolicy::KeepFirst).unwrap();
+        assert_eq!(map["bob"],1);
+
+        let map = SCORES.iter().to_map_policy(MergePolicy::KeepLast).unwrap();
+        assert_eq!(map,SCORES.iter().to_map());
+
+        let err = SCORES.iter().cloned().into_map_policy(MergePolicy::Error).unwrap_err();
+        assert_eq!(err,DuplicateKey{key: "bob", first: 0, second: 2});
+    }
+
+    // deliberately not Clone
+    #[cfg(feature = "std")]
+    #[derive(PartialEq,Eq,Hash,Debug)]
+    struct Record {
+        id: u32,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_map() {
+        let map = vec![Record{id: 1},Record{id: 2}].into_iter()
+            .map(|r| (r.id, r)).into_map();
+
+        assert_eq!(map.len(),2);
+        assert_eq!(map[&2],Record{id: 2});
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_set() {
+        let set = vec![Record{id: 1},Record{id: 2},Record{id: 1}].into_iter().into_set();
+
+        assert_eq!(set.len(),2);
+        assert!(set.contains(&Record{id: 1}));
+    }
+
+    #[test]
+    fn test_to_btree_map() {
+        let map = VALUES.iter().to_btree_map();
+
+        assert_eq!(map.get("hello"),Some(&10));
+        assert_eq!(map.keys().to_vec(),&[&"dolly",&"hello"]);
+
+        let map = VALUES.iter().cloned().into_btree_map();
+        assert_eq!(map.values().to_vec(),&[&20,&10]);
+    }
+
+    #[test]
+    fn test_to_btree_set() {
+        let set = [10,5,2,5,10].iter().to_btree_set();
+        assert_eq!(set.iter().to_vec(),&[&2,&5,&10]);
+
+        // owned values need not be Clone
+        #[derive(PartialEq,Eq,PartialOrd,Ord,Debug)]
+        struct Id(u32);
+        let set = vec![Id(3),Id(1),Id(3)].into_iter().into_btree_set();
+        assert_eq!(set.into_iter().to_vec(),&[Id(1),Id(3)]);
+    }
+
 
 }
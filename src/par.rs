@@ -0,0 +1,109 @@
+//! The same collectors for rayon's `ParallelIterator`.
+//!
+//! These are the parallel versions of `ToVec`, `ToVecResult`, `ToMap`
+//! and `ToSet`, with the same implicit cloning of references. Like
+//! rayon's own `collect`, the vector collectors preserve the original
+//! order.
+
+use rayon::iter::ParallelIterator;
+use alloc::vec::Vec;
+use std::collections::{HashMap,HashSet};
+use core::hash::Hash;
+
+/// to_vec() method on parallel iterators
+pub trait ParToVec<T> {
+    /// collect a parallel iterator's values into a Vec
+    fn to_vec(self) -> Vec<T>;
+}
+
+/// to_vec_result() method on parallel iterators
+pub trait ParToVecResult<T,E> {
+    /// this collects a parallel iterator of `Result<T,E>`
+    /// into a result of `Result<Vec<T>,E>`
+    fn to_vec_result(self) -> Result<Vec<T>,E>;
+}
+
+/// to_map() method on parallel iterators of references
+pub trait ParToMap<K,V> {
+    /// collect references into a HashMap by cloning
+    fn to_map(self) -> HashMap<K,V>;
+}
+
+/// to_set() method on parallel iterators of references
+pub trait ParToSet<K> {
+    /// collect values into a HashSet by cloning
+    fn to_set(self) -> HashSet<K>;
+}
+
+impl <T,I> ParToVec<T> for I
+where T: Send, I: ParallelIterator<Item=T> {
+    fn to_vec(self) -> Vec<T> {
+        self.collect()
+    }
+}
+
+impl <T,E,I> ParToVecResult<T,E> for I
+where T: Send, E: Send, I: ParallelIterator<Item=Result<T,E>> {
+    fn to_vec_result(self) -> Result<Vec<T>,E> {
+        self.collect()
+    }
+}
+
+impl <'a, K,V,I> ParToMap<K,V> for I
+where K: Eq + Hash + Clone + Send + Sync +'a, V: Clone + Send + Sync +'a,
+      I: ParallelIterator<Item=&'a (K,V)> {
+    fn to_map(self) -> HashMap<K,V> {
+        self.cloned().collect()
+    }
+}
+
+impl <'a, K,I> ParToSet<K> for I
+where K: Eq + Hash + Clone + Send + Sync +'a, I: ParallelIterator<Item=&'a K> {
+    fn to_set(self) -> HashSet<K> {
+        self.cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+    use ToVec;
+
+    #[test]
+    fn test_par_to_vec() {
+        let v = (0..10_000).into_par_iter().map(|i| i * 2).to_vec();
+        assert_eq!(v,(0..10_000).map(|i| i * 2).to_vec());
+
+        let words = ["one","two","three"];
+        let v = words.par_iter().map(|s| s.len()).to_vec();
+        assert_eq!(v,&[3,3,5]);
+    }
+
+    #[test]
+    fn test_par_to_vec_result() {
+        let numbers = ["23E","5F5","FF00"].par_iter()
+            .map(|s| u32::from_str_radix(s,16)).to_vec_result().unwrap();
+        assert_eq!(numbers,&[0x23E, 0x5F5, 0xFF00]);
+
+        let res = ["23E","X","FF00"].par_iter()
+            .map(|s| u32::from_str_radix(s,16)).to_vec_result();
+        assert!(res.is_err());
+    }
+
+    const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+
+    #[test]
+    fn test_par_to_map() {
+        let map = VALUES.par_iter().to_map();
+        assert_eq!(map.get("hello"),Some(&10));
+        assert_eq!(map.get("dolly"),Some(&20));
+    }
+
+    #[test]
+    fn test_par_to_set() {
+        let set = [10,5,2,5,10].par_iter().to_set();
+        assert_eq!(set.len(),3);
+        assert!(set.contains(&5));
+    }
+}
@@ -0,0 +1,122 @@
+//! Collecting into `VecDeque` and `BinaryHeap`.
+
+use alloc::collections::{VecDeque,BinaryHeap};
+use core::iter::FromIterator;
+
+/// to_deque() method on iterators
+pub trait ToDeque<T> {
+    /// collect an iterator's values into a VecDeque
+    fn to_deque(self) -> VecDeque<T>;
+}
+
+/// to_deque_cloned() method on iterators of references
+pub trait ToDequeCloned<T> {
+    /// collect references into a VecDeque by cloning
+    fn to_deque_cloned(self) -> VecDeque<T>;
+}
+
+/// to_deque_result() method on iterators
+pub trait ToDequeResult<T,E> {
+    /// this collects an iterator of `Result<T,E>`
+    /// into a result of `Result<VecDeque<T>,E>`
+    fn to_deque_result(self) -> Result<VecDeque<T>,E>;
+}
+
+/// to_heap() method on iterators
+pub trait ToHeap<T> {
+    /// collect an iterator's values into a BinaryHeap
+    fn to_heap(self) -> BinaryHeap<T>;
+}
+
+/// to_heap_cloned() method on iterators of references
+pub trait ToHeapCloned<T> {
+    /// collect references into a BinaryHeap by cloning
+    fn to_heap_cloned(self) -> BinaryHeap<T>;
+}
+
+/// to_heap_result() method on iterators
+pub trait ToHeapResult<T,E> {
+    /// this collects an iterator of `Result<T,E>`
+    /// into a result of `Result<BinaryHeap<T>,E>`
+    fn to_heap_result(self) -> Result<BinaryHeap<T>,E>;
+}
+
+impl <T,I> ToDeque<T> for I
+where I: Iterator<Item=T> {
+    fn to_deque(self) -> VecDeque<T> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <'a, T,I> ToDequeCloned<T> for I
+where T: Clone +'a, I: Iterator<Item=&'a T> {
+    fn to_deque_cloned(self) -> VecDeque<T> {
+        FromIterator::from_iter(self.cloned())
+    }
+}
+
+impl <T,E,I> ToDequeResult<T,E> for I
+where I: Iterator<Item=Result<T,E>> {
+    fn to_deque_result(self) -> Result<VecDeque<T>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <T,I> ToHeap<T> for I
+where T: Ord, I: Iterator<Item=T> {
+    fn to_heap(self) -> BinaryHeap<T> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <'a, T,I> ToHeapCloned<T> for I
+where T: Ord + Clone +'a, I: Iterator<Item=&'a T> {
+    fn to_heap_cloned(self) -> BinaryHeap<T> {
+        FromIterator::from_iter(self.cloned())
+    }
+}
+
+impl <T,E,I> ToHeapResult<T,E> for I
+where T: Ord, I: Iterator<Item=Result<T,E>> {
+    fn to_heap_result(self) -> Result<BinaryHeap<T>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Reverse;
+
+    #[test]
+    fn test_to_deque() {
+        let mut q = "one two three".split_whitespace().to_deque();
+        q.push_front("zero");
+        assert_eq!(q,&["zero","one","two","three"]);
+
+        let q = [1,2,3].iter().to_deque_cloned();
+        assert_eq!(q.back(),Some(&3));
+
+        let q = "1 2".split_whitespace().map(|s| s.parse::<i32>()).to_deque_result();
+        assert_eq!(q.unwrap(),&[1,2]);
+        assert!("1 x".split_whitespace().map(|s| s.parse::<i32>()).to_deque_result().is_err());
+    }
+
+    #[test]
+    fn test_to_heap() {
+        let mut heap = vec![3,1,4,1,5].into_iter().to_heap();
+        assert_eq!(heap.pop(),Some(5));
+        assert_eq!(heap.pop(),Some(4));
+
+        // smallest first, as a scheduler would want
+        let mut heap = [3,1,4].iter().map(|&i| Reverse(i)).to_heap();
+        assert_eq!(heap.pop(),Some(Reverse(1)));
+
+        let heap = [3,1,4].iter().to_heap_cloned();
+        assert_eq!(heap.into_sorted_vec(),&[1,3,4]);
+
+        let heap = "3 1".split_whitespace().map(|s| s.parse::<i32>()).to_heap_result();
+        assert_eq!(heap.unwrap().peek(),Some(&3));
+        assert!("1 x".split_whitespace().map(|s| s.parse::<i32>()).to_heap_result().is_err());
+    }
+}
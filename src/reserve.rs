@@ -0,0 +1,137 @@
+//! Collectors which report allocation failure instead of aborting.
+//!
+//! These are all called `try_alloc_*`, since `try_to_map` and friends
+//! are about duplicate keys, not memory.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap,HashSet};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use error::AllocError;
+
+/// try_alloc_to_vec() method on iterators
+pub trait TryAllocToVec<T> {
+    /// collect into a Vec, returning an error if memory runs out
+    fn try_alloc_to_vec(self) -> Result<Vec<T>,AllocError>;
+}
+
+/// try_alloc_to_set() method on iterators of references
+#[cfg(feature = "std")]
+pub trait TryAllocToSet<K> {
+    /// collect values into a HashSet by cloning,
+    /// returning an error if memory runs out
+    fn try_alloc_to_set(self) -> Result<HashSet<K>,AllocError>;
+}
+
+/// try_alloc_to_map() method on iterators of references
+#[cfg(feature = "std")]
+pub trait TryAllocToMap<K,V> {
+    /// collect references into a HashMap by cloning,
+    /// returning an error if memory runs out
+    fn try_alloc_to_map(self) -> Result<HashMap<K,V>,AllocError>;
+}
+
+impl <T,I> TryAllocToVec<T> for I
+where I: Iterator<Item=T> {
+    fn try_alloc_to_vec(self) -> Result<Vec<T>,AllocError> {
+        let mut v = Vec::new();
+        v.try_reserve(self.size_hint().0)
+            .map_err(|source| AllocError{collected: 0, source})?;
+        for t in self {
+            if v.len() == v.capacity() {
+                // try_reserve grows geometrically, just like push
+                v.try_reserve(1)
+                    .map_err(|source| AllocError{collected: v.len(), source})?;
+            }
+            v.push(t);
+        }
+        Ok(v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K,I> TryAllocToSet<K> for I
+where K: Eq + Hash + Clone +'a, I: Iterator<Item=&'a K> {
+    fn try_alloc_to_set(self) -> Result<HashSet<K>,AllocError> {
+        let mut set = HashSet::new();
+        set.try_reserve(self.size_hint().0)
+            .map_err(|source| AllocError{collected: 0, source})?;
+        for k in self {
+            if set.len() == set.capacity() {
+                set.try_reserve(1)
+                    .map_err(|source| AllocError{collected: set.len(), source})?;
+            }
+            set.insert(k.clone());
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K,V,I> TryAllocToMap<K,V> for I
+where K: Eq + Hash + Clone +'a, V: Clone +'a, I: Iterator<Item=&'a (K,V)> {
+    fn try_alloc_to_map(self) -> Result<HashMap<K,V>,AllocError> {
+        let mut map = HashMap::new();
+        map.try_reserve(self.size_hint().0)
+            .map_err(|source| AllocError{collected: 0, source})?;
+        for (k,v) in self.cloned() {
+            if map.len() == map.capacity() {
+                map.try_reserve(1)
+                    .map_err(|source| AllocError{collected: map.len(), source})?;
+            }
+            map.insert(k,v);
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // an iterator which claims it will produce an impossible number of items
+    struct Huge<I>(I);
+
+    impl <I: Iterator> Iterator for Huge<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize,Option<usize>) {
+            (usize::MAX / 2, None)
+        }
+    }
+
+    #[test]
+    fn test_try_alloc_to_vec() {
+        let v = (0..1000).filter(|i| i % 2 == 0).try_alloc_to_vec().unwrap();
+        assert_eq!(v.len(),500);
+
+        let err = Huge(0..10).try_alloc_to_vec().unwrap_err();
+        assert_eq!(err.collected,0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_alloc_to_set() {
+        let set = [10,5,2,5,10].iter().try_alloc_to_set().unwrap();
+        assert_eq!(set.len(),3);
+
+        assert!(Huge([1,2].iter()).try_alloc_to_set().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_alloc_to_map() {
+        use alloc::string::ToString;
+        const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+        let map = VALUES.iter().try_alloc_to_map().unwrap();
+        assert_eq!(map["dolly"],20);
+
+        let err = Huge(VALUES.iter()).try_alloc_to_map().unwrap_err();
+        assert!(err.to_string().starts_with("allocation failed after 0 items"));
+    }
+}
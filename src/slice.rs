@@ -0,0 +1,170 @@
+//! Collecting into boxed and shared slices and strings.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::string::String;
+use core::iter::FromIterator;
+
+/// to_boxed_slice() and friends on iterators
+pub trait ToSlice<T> {
+    /// collect an iterator's values into a `Box<[T]>`
+    fn to_boxed_slice(self) -> Box<[T]>;
+
+    /// collect an iterator's values into an `Rc<[T]>`
+    fn to_rc_slice(self) -> Rc<[T]>;
+
+    /// collect an iterator's values into an `Arc<[T]>`
+    fn to_arc_slice(self) -> Arc<[T]>;
+}
+
+/// to_boxed_slice_result() and friends on iterators
+pub trait ToSliceResult<T,E> {
+    /// this collects an iterator of `Result<T,E>`
+    /// into a result of `Result<Box<[T]>,E>`
+    fn to_boxed_slice_result(self) -> Result<Box<[T]>,E>;
+
+    /// this collects an iterator of `Result<T,E>`
+    /// into a result of `Result<Rc<[T]>,E>`
+    fn to_rc_slice_result(self) -> Result<Rc<[T]>,E>;
+
+    /// this collects an iterator of `Result<T,E>`
+    /// into a result of `Result<Arc<[T]>,E>`
+    fn to_arc_slice_result(self) -> Result<Arc<[T]>,E>;
+}
+
+/// to_arc_str() and friends on iterators of chars and strings
+pub trait ToSharedStr {
+    /// collect chars or string slices into a `Box<str>`
+    fn to_boxed_str(self) -> Box<str>;
+
+    /// collect chars or string slices into an `Rc<str>`
+    fn to_rc_str(self) -> Rc<str>;
+
+    /// collect chars or string slices into an `Arc<str>`
+    fn to_arc_str(self) -> Arc<str>;
+}
+
+/// to_arc_str_result() and friends on iterators
+pub trait ToSharedStrResult<E> {
+    /// this collects an iterator of `Result<T,E>`, where `T` is a char
+    /// or string slice, into a result of `Result<Box<str>,E>`
+    fn to_boxed_str_result(self) -> Result<Box<str>,E>;
+
+    /// this collects an iterator of `Result<T,E>`, where `T` is a char
+    /// or string slice, into a result of `Result<Rc<str>,E>`
+    fn to_rc_str_result(self) -> Result<Rc<str>,E>;
+
+    /// this collects an iterator of `Result<T,E>`, where `T` is a char
+    /// or string slice, into a result of `Result<Arc<str>,E>`
+    fn to_arc_str_result(self) -> Result<Arc<str>,E>;
+}
+
+impl <T,I> ToSlice<T> for I
+where I: Iterator<Item=T> {
+    fn to_boxed_slice(self) -> Box<[T]> {
+        FromIterator::from_iter(self)
+    }
+
+    fn to_rc_slice(self) -> Rc<[T]> {
+        FromIterator::from_iter(self)
+    }
+
+    fn to_arc_slice(self) -> Arc<[T]> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <T,E,I> ToSliceResult<T,E> for I
+where I: Iterator<Item=Result<T,E>> {
+    fn to_boxed_slice_result(self) -> Result<Box<[T]>,E> {
+        FromIterator::from_iter(self)
+    }
+
+    fn to_rc_slice_result(self) -> Result<Rc<[T]>,E> {
+        FromIterator::from_iter(self)
+    }
+
+    fn to_arc_slice_result(self) -> Result<Arc<[T]>,E> {
+        FromIterator::from_iter(self)
+    }
+}
+
+impl <T,I> ToSharedStr for I
+where String: FromIterator<T>, I: Iterator<Item=T> {
+    fn to_boxed_str(self) -> Box<str> {
+        String::from_iter(self).into_boxed_str()
+    }
+
+    fn to_rc_str(self) -> Rc<str> {
+        Rc::from(String::from_iter(self))
+    }
+
+    fn to_arc_str(self) -> Arc<str> {
+        Arc::from(String::from_iter(self))
+    }
+}
+
+impl <T,E,I> ToSharedStrResult<E> for I
+where String: FromIterator<T>, I: Iterator<Item=Result<T,E>> {
+    fn to_boxed_str_result(self) -> Result<Box<str>,E> {
+        self.collect::<Result<String,E>>().map(String::into_boxed_str)
+    }
+
+    fn to_rc_str_result(self) -> Result<Rc<str>,E> {
+        self.collect::<Result<String,E>>().map(Rc::from)
+    }
+
+    fn to_arc_str_result(self) -> Result<Arc<str>,E> {
+        self.collect::<Result<String,E>>().map(Arc::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_slice() {
+        let b = (1..4).to_boxed_slice();
+        assert_eq!(&*b,&[1,2,3]);
+
+        let rc = "one two".split_whitespace().to_rc_slice();
+        assert_eq!(&*rc,&["one","two"]);
+
+        let arc = (1..4).map(|i| i * i).to_arc_slice();
+        let shared = Arc::clone(&arc);
+        assert_eq!(&*shared,&[1,4,9]);
+    }
+
+    #[test]
+    fn test_to_slice_result() {
+        let parse = |s: &'static str| s.split_whitespace().map(|s| s.parse::<i32>());
+
+        assert_eq!(&*parse("1 2").to_boxed_slice_result().unwrap(),&[1,2]);
+        assert_eq!(&*parse("1 2").to_rc_slice_result().unwrap(),&[1,2]);
+        assert_eq!(&*parse("1 2").to_arc_slice_result().unwrap(),&[1,2]);
+
+        assert!(parse("1 x").to_boxed_slice_result().is_err());
+        assert!(parse("1 x").to_rc_slice_result().is_err());
+        assert!(parse("1 x").to_arc_slice_result().is_err());
+    }
+
+    #[test]
+    fn test_to_shared_str() {
+        assert_eq!(&*"hello".chars().rev().to_boxed_str(),"olleh");
+        assert_eq!(&*["a","b"].iter().cloned().to_rc_str(),"ab");
+        assert_eq!(&*"abc".chars().map(|c| c.to_ascii_uppercase()).to_arc_str(),"ABC");
+
+        let hex = |s: &'static str| s.split_whitespace()
+            .map(|s| u32::from_str_radix(s,16).map(|n| char::from_u32(n).unwrap()));
+
+        assert_eq!(&*hex("68 69").to_boxed_str_result().unwrap(),"hi");
+        assert_eq!(&*hex("68 69").to_rc_str_result().unwrap(),"hi");
+        assert_eq!(&*hex("68 69").to_arc_str_result().unwrap(),"hi");
+
+        assert!(hex("68 zz").to_boxed_str_result().is_err());
+        assert!(hex("68 zz").to_rc_str_result().is_err());
+        assert!(hex("68 zz").to_arc_str_result().is_err());
+    }
+}
@@ -0,0 +1,127 @@
+//! Collecting into sorted vectors.
+
+use alloc::vec::Vec;
+
+/// to_sorted_vec() and friends on iterators
+pub trait ToSortedVec<T> {
+    /// collect into a Vec and sort it (stable)
+    fn to_sorted_vec(self) -> Vec<T>
+    where T: Ord;
+
+    /// collect into a Vec and sort it by `key(&item)` (stable)
+    fn to_sorted_vec_by_key<K,F>(self, key: F) -> Vec<T>
+    where K: Ord, F: FnMut(&T) -> K;
+
+    /// collect into a Vec, sort it and remove duplicates
+    fn to_sorted_dedup_vec(self) -> Vec<T>
+    where T: Ord;
+
+    /// collect into a Vec and sort it, not preserving the
+    /// order of equal values, which is usually faster
+    fn to_unstable_sorted_vec(self) -> Vec<T>
+    where T: Ord;
+}
+
+/// to_sorted_vec_cloned() and friends on iterators of references
+pub trait ToSortedVecCloned<T> {
+    /// clone references into a Vec and sort it (stable)
+    fn to_sorted_vec_cloned(self) -> Vec<T>
+    where T: Ord;
+
+    /// clone references into a Vec and sort it by `key(&item)` (stable)
+    fn to_sorted_vec_by_key_cloned<K,F>(self, key: F) -> Vec<T>
+    where K: Ord, F: FnMut(&T) -> K;
+
+    /// clone references into a Vec, sort it and remove duplicates
+    fn to_sorted_dedup_vec_cloned(self) -> Vec<T>
+    where T: Ord;
+
+    /// clone references into a Vec and sort it, not preserving
+    /// the order of equal values
+    fn to_unstable_sorted_vec_cloned(self) -> Vec<T>
+    where T: Ord;
+}
+
+impl <T,I> ToSortedVec<T> for I
+where I: Iterator<Item=T> {
+    fn to_sorted_vec(self) -> Vec<T>
+    where T: Ord {
+        let mut v: Vec<T> = self.collect();
+        v.sort();
+        v
+    }
+
+    fn to_sorted_vec_by_key<K,F>(self, key: F) -> Vec<T>
+    where K: Ord, F: FnMut(&T) -> K {
+        let mut v: Vec<T> = self.collect();
+        v.sort_by_key(key);
+        v
+    }
+
+    fn to_sorted_dedup_vec(self) -> Vec<T>
+    where T: Ord {
+        let mut v = self.to_unstable_sorted_vec();
+        v.dedup();
+        v
+    }
+
+    fn to_unstable_sorted_vec(self) -> Vec<T>
+    where T: Ord {
+        let mut v: Vec<T> = self.collect();
+        v.sort_unstable();
+        v
+    }
+}
+
+impl <'a, T,I> ToSortedVecCloned<T> for I
+where T: Clone +'a, I: Iterator<Item=&'a T> {
+    fn to_sorted_vec_cloned(self) -> Vec<T>
+    where T: Ord {
+        self.cloned().to_sorted_vec()
+    }
+
+    fn to_sorted_vec_by_key_cloned<K,F>(self, key: F) -> Vec<T>
+    where K: Ord, F: FnMut(&T) -> K {
+        self.cloned().to_sorted_vec_by_key(key)
+    }
+
+    fn to_sorted_dedup_vec_cloned(self) -> Vec<T>
+    where T: Ord {
+        self.cloned().to_sorted_dedup_vec()
+    }
+
+    fn to_unstable_sorted_vec_cloned(self) -> Vec<T>
+    where T: Ord {
+        self.cloned().to_unstable_sorted_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_sorted_vec() {
+        let v = "one two three four".split_whitespace().to_sorted_vec();
+        assert_eq!(v,&["four","one","three","two"]);
+
+        // stable, so 'one' stays before 'two'
+        let v = "one two three four".split_whitespace().to_sorted_vec_by_key(|s| s.len());
+        assert_eq!(v,&["one","two","four","three"]);
+
+        let v = [3,1,3,2,1].iter().to_sorted_dedup_vec();
+        assert_eq!(v,&[&1,&2,&3]);
+
+        let v = vec![3,1,2].into_iter().to_unstable_sorted_vec();
+        assert_eq!(v,&[1,2,3]);
+    }
+
+    #[test]
+    fn test_to_sorted_vec_cloned() {
+        let values = [3,1,3,2,1];
+        assert_eq!(values.iter().to_sorted_vec_cloned(),&[1,1,2,3,3]);
+        assert_eq!(values.iter().to_sorted_vec_by_key_cloned(|&x| -x),&[3,3,2,1,1]);
+        assert_eq!(values.iter().to_sorted_dedup_vec_cloned(),&[1,2,3]);
+        assert_eq!(values.iter().to_unstable_sorted_vec_cloned(),&[1,1,2,3,3]);
+    }
+}
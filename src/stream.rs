@@ -0,0 +1,109 @@
+//! The same collectors for `futures::Stream`.
+//!
+//! These are the asynchronous versions of `ToVec`, `ToVecResult`, `ToMap`
+//! and `ToSet`. Each method returns a future which resolves to the
+//! collection, so `stream.to_vec().await` gives you a `Vec`.
+
+use futures::stream::{Stream,StreamExt,TryStream,TryStreamExt,Collect,TryCollect,Map};
+use alloc::vec::Vec;
+use std::collections::{HashMap,HashSet};
+use core::hash::Hash;
+
+/// the future returned by `StreamToMap::to_map`
+pub type ToMapFuture<'a,S,K,V> = Collect<Map<S,fn(&'a (K,V)) -> (K,V)>,HashMap<K,V>>;
+
+/// the future returned by `StreamToSet::to_set`
+pub type ToSetFuture<'a,S,K> = Collect<Map<S,fn(&'a K) -> K>,HashSet<K>>;
+
+/// to_vec() method on streams
+pub trait StreamToVec<T> : Sized {
+    /// a future which collects a stream's values into a Vec
+    fn to_vec(self) -> Collect<Self,Vec<T>>;
+}
+
+/// to_vec_result() method on streams
+pub trait StreamToVecResult<T,E> : Sized {
+    /// a future which collects a stream of `Result<T,E>`
+    /// into a result of `Result<Vec<T>,E>`
+    fn to_vec_result(self) -> TryCollect<Self,Vec<T>>;
+}
+
+/// to_map() method on streams of references
+pub trait StreamToMap<'a,K,V> : Sized where K: 'a, V: 'a {
+    /// a future which collects references into a HashMap by cloning
+    fn to_map(self) -> ToMapFuture<'a,Self,K,V>;
+}
+
+/// to_set() method on streams of references
+pub trait StreamToSet<'a,K> : Sized where K: 'a {
+    /// a future which collects values into a HashSet by cloning
+    fn to_set(self) -> ToSetFuture<'a,Self,K>;
+}
+
+impl <T,S> StreamToVec<T> for S
+where S: Stream<Item=T> {
+    fn to_vec(self) -> Collect<Self,Vec<T>> {
+        self.collect()
+    }
+}
+
+impl <T,E,S> StreamToVecResult<T,E> for S
+where S: TryStream<Ok=T,Error=E> {
+    fn to_vec_result(self) -> TryCollect<Self,Vec<T>> {
+        self.try_collect()
+    }
+}
+
+impl <'a, K,V,S> StreamToMap<'a,K,V> for S
+where K: Eq + Hash + Clone +'a, V: Clone +'a, S: Stream<Item=&'a (K,V)> {
+    fn to_map(self) -> ToMapFuture<'a,Self,K,V> {
+        self.map(Clone::clone as fn(&'a (K,V)) -> (K,V)).collect()
+    }
+}
+
+impl <'a, K,S> StreamToSet<'a,K> for S
+where K: Eq + Hash + Clone +'a, S: Stream<Item=&'a K> {
+    fn to_set(self) -> ToSetFuture<'a,Self,K> {
+        self.map(Clone::clone as fn(&'a K) -> K).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::stream;
+
+    #[test]
+    fn test_stream_to_vec() {
+        let v = block_on(stream::iter(vec![1,2,3]).map(|i| i * 2).to_vec());
+        assert_eq!(v,&[2,4,6]);
+    }
+
+    #[test]
+    fn test_stream_to_vec_result() {
+        let numbers = block_on(stream::iter(vec!["23E","5F5","FF00"])
+            .map(|s| u32::from_str_radix(s,16)).to_vec_result()).unwrap();
+        assert_eq!(numbers,&[0x23E, 0x5F5, 0xFF00]);
+
+        let res = block_on(stream::iter(vec!["23E","X","FF00"])
+            .map(|s| u32::from_str_radix(s,16)).to_vec_result());
+        assert!(res.is_err());
+    }
+
+    const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+
+    #[test]
+    fn test_stream_to_map() {
+        let map = block_on(stream::iter(VALUES).to_map());
+        assert_eq!(map.get("hello"),Some(&10));
+        assert_eq!(map.get("dolly"),Some(&20));
+    }
+
+    #[test]
+    fn test_stream_to_set() {
+        let set = block_on(stream::iter(&[10,5,2,5,10]).to_set());
+        assert_eq!(set.len(),3);
+        assert!(set.contains(&5));
+    }
+}
@@ -0,0 +1,99 @@
+//! Removing duplicates while keeping the original order.
+
+use alloc::vec::Vec;
+use std::collections::HashSet;
+use core::hash::Hash;
+
+/// to_unique_vec() methods on iterators
+pub trait ToUniqueVec<T> {
+    /// collect the first occurrence of each value into a Vec,
+    /// keeping the original order
+    fn to_unique_vec(self) -> Vec<T>
+    where T: Eq + Hash;
+
+    /// collect the first item for each `key(&item)` into a Vec,
+    /// keeping the original order
+    fn to_unique_vec_by_key<K,F>(self, key: F) -> Vec<T>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+}
+
+/// to_unique_vec_cloned() methods on iterators of references
+pub trait ToUniqueVecCloned<T> {
+    /// clone the first occurrence of each value into a Vec,
+    /// keeping the original order
+    fn to_unique_vec_cloned(self) -> Vec<T>
+    where T: Eq + Hash;
+
+    /// clone the first item for each `key(&item)` into a Vec,
+    /// keeping the original order
+    fn to_unique_vec_by_key_cloned<K,F>(self, key: F) -> Vec<T>
+    where K: Eq + Hash, F: FnMut(&T) -> K;
+}
+
+impl <T,I> ToUniqueVec<T> for I
+where I: Iterator<Item=T> {
+    fn to_unique_vec(self) -> Vec<T>
+    where T: Eq + Hash {
+        // collect first, so that the set can borrow the values
+        // rather than needing them to be Clone
+        let mut v: Vec<T> = self.collect();
+        let keep: Vec<bool> = {
+            let mut seen = HashSet::with_capacity(v.len());
+            v.iter().map(|t| seen.insert(t)).collect()
+        };
+        let mut keep = keep.into_iter();
+        v.retain(|_| keep.next().unwrap_or(false));
+        v
+    }
+
+    fn to_unique_vec_by_key<K,F>(self, mut key: F) -> Vec<T>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        let mut seen = HashSet::new();
+        self.filter(|t| seen.insert(key(t))).collect()
+    }
+}
+
+impl <'a, T,I> ToUniqueVecCloned<T> for I
+where T: Clone +'a, I: Iterator<Item=&'a T> {
+    fn to_unique_vec_cloned(self) -> Vec<T>
+    where T: Eq + Hash {
+        // only the references need to go into the set
+        let mut seen = HashSet::new();
+        self.filter(|t| seen.insert(*t)).cloned().collect()
+    }
+
+    fn to_unique_vec_by_key_cloned<K,F>(self, mut key: F) -> Vec<T>
+    where K: Eq + Hash, F: FnMut(&T) -> K {
+        let mut seen = HashSet::new();
+        self.filter(|t| seen.insert(key(t))).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_unique_vec_cloned() {
+        let args = ["-v","--color","-v","-q","--color"];
+        assert_eq!(args.iter().to_unique_vec_cloned(),&["-v","--color","-q"]);
+
+        let v = args.iter().to_unique_vec_by_key_cloned(|s| s.starts_with("--"));
+        assert_eq!(v,&["-v","--color"]);
+    }
+
+    // deliberately not Clone
+    #[derive(Debug,PartialEq,Eq,Hash)]
+    struct Import(&'static str, u32);
+
+    #[test]
+    fn test_to_unique_vec() {
+        let imports = vec![Import("a",1),Import("b",1),Import("a",1),Import("a",2)];
+        assert_eq!(imports.into_iter().to_unique_vec(),
+            &[Import("a",1),Import("b",1),Import("a",2)]);
+
+        let imports = vec![Import("a",1),Import("b",1),Import("a",2)];
+        assert_eq!(imports.into_iter().to_unique_vec_by_key(|i| i.0),
+            &[Import("a",1),Import("b",1)]);
+    }
+}
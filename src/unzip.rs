@@ -0,0 +1,169 @@
+//! Splitting iterators of pairs and triples into vectors and maps.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
+/// a pair, or a reference to a pair which will be cloned
+pub trait UnzipPair {
+    /// type of the first value
+    type A;
+    /// type of the second value
+    type B;
+    /// the owned pair
+    fn into_pair(self) -> (Self::A,Self::B);
+}
+
+/// a triple, or a reference to a triple which will be cloned
+pub trait UnzipTriple {
+    /// type of the first value
+    type A;
+    /// type of the second value
+    type B;
+    /// type of the third value
+    type C;
+    /// the owned triple
+    fn into_triple(self) -> (Self::A,Self::B,Self::C);
+}
+
+impl <A,B> UnzipPair for (A,B) {
+    type A = A;
+    type B = B;
+    fn into_pair(self) -> (A,B) {
+        self
+    }
+}
+
+impl <A,B> UnzipPair for &(A,B)
+where A: Clone, B: Clone {
+    type A = A;
+    type B = B;
+    fn into_pair(self) -> (A,B) {
+        self.clone()
+    }
+}
+
+impl <A,B,C> UnzipTriple for (A,B,C) {
+    type A = A;
+    type B = B;
+    type C = C;
+    fn into_triple(self) -> (A,B,C) {
+        self
+    }
+}
+
+impl <A,B,C> UnzipTriple for &(A,B,C)
+where A: Clone, B: Clone, C: Clone {
+    type A = A;
+    type B = B;
+    type C = C;
+    fn into_triple(self) -> (A,B,C) {
+        self.clone()
+    }
+}
+
+/// to_vec_unzip() method on iterators of pairs
+pub trait ToVecUnzip<A,B> {
+    /// split pairs (or references to pairs, by cloning) into two Vecs
+    fn to_vec_unzip(self) -> (Vec<A>,Vec<B>);
+}
+
+/// to_vec_unzip3() method on iterators of triples
+pub trait ToVecUnzip3<A,B,C> {
+    /// split triples (or references to triples, by cloning) into three Vecs
+    fn to_vec_unzip3(self) -> (Vec<A>,Vec<B>,Vec<C>);
+}
+
+/// to_map_unzip() method on iterators of keys and pairs
+#[cfg(feature = "std")]
+pub trait ToMapUnzip<K,A,B> {
+    /// split `(key,(a,b))` items (or references to them, by cloning)
+    /// into two HashMaps with the same keys
+    fn to_map_unzip(self) -> (HashMap<K,A>,HashMap<K,B>);
+}
+
+impl <P,I> ToVecUnzip<P::A,P::B> for I
+where P: UnzipPair, I: Iterator<Item=P> {
+    fn to_vec_unzip(self) -> (Vec<P::A>,Vec<P::B>) {
+        self.map(UnzipPair::into_pair).unzip()
+    }
+}
+
+impl <T,I> ToVecUnzip3<T::A,T::B,T::C> for I
+where T: UnzipTriple, I: Iterator<Item=T> {
+    fn to_vec_unzip3(self) -> (Vec<T::A>,Vec<T::B>,Vec<T::C>) {
+        let n = self.size_hint().0;
+        let (mut a, mut b, mut c) = (Vec::with_capacity(n),Vec::with_capacity(n),Vec::with_capacity(n));
+        for t in self {
+            let (x,y,z) = t.into_triple();
+            a.push(x);
+            b.push(y);
+            c.push(z);
+        }
+        (a,b,c)
+    }
+}
+
+#[cfg(feature = "std")]
+impl <P,I> ToMapUnzip<P::A,<P::B as UnzipPair>::A,<P::B as UnzipPair>::B> for I
+where P: UnzipPair, P::A: Eq + Hash + Clone, P::B: UnzipPair, I: Iterator<Item=P> {
+    fn to_map_unzip(self) -> (HashMap<P::A,<P::B as UnzipPair>::A>,HashMap<P::A,<P::B as UnzipPair>::B>) {
+        let n = self.size_hint().0;
+        let (mut a, mut b) = (HashMap::with_capacity(n),HashMap::with_capacity(n));
+        for p in self {
+            let (k,v) = p.into_pair();
+            let (x,y) = v.into_pair();
+            a.insert(k.clone(),x);
+            b.insert(k,y);
+        }
+        (a,b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: &[(&str,i32)] = &[("hello",10),("dolly",20)];
+
+    #[test]
+    fn test_to_vec_unzip() {
+        let (names,values) = VALUES.iter().to_vec_unzip();
+        assert_eq!(names,&["hello","dolly"]);
+        assert_eq!(values,&[10,20]);
+
+        let (i,sq) = (1..4).map(|i| (i,i*i)).to_vec_unzip();
+        assert_eq!(i,&[1,2,3]);
+        assert_eq!(sq,&[1,4,9]);
+    }
+
+    #[test]
+    fn test_to_vec_unzip3() {
+        let rgb = [(255,0,0),(0,128,255)];
+        let (r,g,b) = rgb.iter().to_vec_unzip3();
+        assert_eq!(r,&[255,0]);
+        assert_eq!(g,&[0,128]);
+        assert_eq!(b,&[0,255]);
+
+        let (a,b,c) = (0..3).map(|i| (i,i as f64,i % 2 == 0)).to_vec_unzip3();
+        assert_eq!(a,&[0,1,2]);
+        assert_eq!(b,&[0.0,1.0,2.0]);
+        assert_eq!(c,&[true,false,true]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_map_unzip() {
+        const STOCK: &[(&str,(u32,f64))] = &[("apple",(10,0.5)),("pear",(4,0.75))];
+        let (count,price) = STOCK.iter().to_map_unzip();
+        assert_eq!(count["apple"],10);
+        assert_eq!(price["pear"],0.75);
+
+        let (sq,cube) = (1..4).map(|i| (i,(i*i,i*i*i))).to_map_unzip();
+        assert_eq!(sq[&3],9);
+        assert_eq!(cube[&2],8);
+        assert_eq!(sq.len(),3);
+    }
+}